@@ -0,0 +1,134 @@
+#!/bin/sh
+#
+# Regenerates src/seq_ioctl/layout.rs from data/asequencer.h
+#
+#   data/seq_layout.sh          rewrite layout.rs
+#   data/seq_layout.sh --check  fail if layout.rs is out of date with the
+#                               header or asequencer_commit_sha
+#
+# Offsets are taken by compiling the header with $CC (default cc) for
+# -m64 and -m32. Only assembly is produced, so no 32 bit libc is needed.
+
+set -eu
+
+cd "$(dirname "$0")/.."
+
+CC=${CC:-cc}
+HEADER=data/asequencer.h
+OUT=src/seq_ioctl/layout.rs
+SHA=$(cat data/asequencer_commit_sha)
+VERSION=$(sed -n 's/^#define SNDRV_SEQ_VERSION SNDRV_PROTOCOL_VERSION(\(.*\), \(.*\), \(.*\))$/\1.\2.\3/p' "$HEADER")
+
+# Rust type | C type | checked fields, `rust_name:c_name` when they differ
+#
+# Anonymous unions without a member name (`UmpEventData`) are covered by
+# the offsets of their parent.
+TYPES='
+SndTimerId|struct snd_timer_id|dev_class dev_sclass card device subdevice
+UmpEndpointInfo|struct snd_ump_endpoint_info|card device flags protocol_caps protocol num_blocks version family_id model_id manufacturer_id sw_revision name product_id
+UmpBlockInfo|struct snd_ump_block_info|card device block_id direction active first_group num_groups midi_ci_version sysex8_streams ui_hint flags name
+Addr|struct snd_seq_addr|client port
+Connect|struct snd_seq_connect|sender dest
+EvNote|struct snd_seq_ev_note|channel note velocity off_velocity duration
+EvCtrl|struct snd_seq_ev_ctrl|channel param value
+EvRaw8|struct snd_seq_ev_raw8|d
+EvRaw32|struct snd_seq_ev_raw32|d
+EvExt|struct snd_seq_ev_ext|len ptr
+EvResult|struct snd_seq_result|event result
+RealTime|struct snd_seq_real_time|tv_sec tv_nsec
+Timestamp|union snd_seq_timestamp|tick time
+QueueSkew|struct snd_seq_queue_skew|value base
+EvQueueControl|struct snd_seq_ev_queue_control|queue param
+EvQueueControlParam|__typeof__(((struct snd_seq_ev_queue_control *)0)->param)|value time position skew d32 d8
+EvQuote|struct snd_seq_ev_quote|origin value event
+EventData|union snd_seq_event_data|note control raw8 raw32 ext queue time addr connect result quote
+Event|struct snd_seq_event|type_:type flags tag queue time source dest data
+UmpEvent|struct snd_seq_ump_event|type_:type flags tag queue time source dest data
+EventBounce|struct snd_seq_event_bounce|err event
+SystemInfo|struct snd_seq_system_info|queues clients ports channels cur_clients cur_queues
+RunningInfo|struct snd_seq_running_info|client big_endian cpu_mode
+ClientInfo|struct snd_seq_client_info|client type_:type name filter multicast_filter event_filter num_ports event_lost card pid midi_version group_filter
+ClientPool|struct snd_seq_client_pool|client output_pool input_pool output_room output_free input_free
+RemoveEvents|struct snd_seq_remove_events|remove_mode time queue dest channel type_:type tag
+PortInfo|struct snd_seq_port_info|addr name capability type_:type midi_channels midi_voices synth_voices read_use write_use kernel flags time_queue direction ump_group
+QueueInfo|struct snd_seq_queue_info|queue owner name flags
+QueueStatus|struct snd_seq_queue_status|queue events tick time running flags
+QueueTempo|struct snd_seq_queue_tempo|queue tempo ppq skew_value skew_base
+QueueTimer|struct snd_seq_queue_timer|queue type_:type timer:u
+QueueTimerUnion|__typeof__(((struct snd_seq_queue_timer *)0)->u)|alsa
+QueueTimerAlsa|__typeof__(((struct snd_seq_queue_timer *)0)->u.alsa)|id resolution
+QueueClient|struct snd_seq_queue_client|queue client used
+PortSubscribe|struct snd_seq_port_subscribe|sender dest voices flags queue
+QuerySubscribe|struct snd_seq_query_subs|root type_:type index num_subs addr queue flags
+ClientUmpInfo|struct snd_seq_client_ump_info|client type_:type info
+'
+
+TMP=$(mktemp -d)
+trap 'rm -rf "$TMP"' EXIT
+
+# One array entry per value, in table order: size, align, offsets...
+# Values are stored +1 so that gcc never folds them into `.zero`.
+{
+    echo '#define __packed __attribute__((packed))'
+    echo "#include \"$PWD/$HEADER\""
+    echo 'unsigned long layout[] = {'
+    echo "$TYPES" | while IFS='|' read -r rust c fields; do
+        [ -n "$rust" ] || continue
+        echo "    sizeof($c) + 1, _Alignof($c) + 1,"
+        for field in $fields; do
+            echo "    __builtin_offsetof($c, ${field#*:}) + 1,"
+        done
+    done
+    echo '};'
+} > "$TMP/layout.c"
+
+values() {
+    "$CC" "$1" -S -o - "$TMP/layout.c" | awk '$1 == ".quad" || $1 == ".long" { print $2 - 1 }'
+}
+
+body() {
+    values "$1" > "$TMP/values"
+    echo "$TYPES" | awk -v values="$TMP/values" -F'|' '
+        NF == 3 {
+            getline size < values
+            getline align < values
+            printf "    assert_layout!(\n        %s,\n        size = %s,\n        align = %s,\n", $1, size, align
+            n = split($3, fields, " ")
+            for (i = 1; i <= n; i++) {
+                getline offset < values
+                split(fields[i], name, ":")
+                printf "        %s = %s,\n", name[1], offset
+            }
+            print "    );"
+        }'
+}
+
+{
+    echo "// Generated by data/seq_layout.sh from data/asequencer.h, do not edit."
+    echo "// SNDRV_SEQ_VERSION $VERSION, asequencer_commit_sha $SHA"
+    echo
+    echo "use super::types::*;"
+    echo
+    echo '#[cfg(target_pointer_width = "64")]'
+    echo "mod lp64 {"
+    echo "    use super::*;"
+    echo
+    body -m64
+    echo "}"
+    echo
+    echo '#[cfg(target_pointer_width = "32")]'
+    echo "mod ilp32 {"
+    echo "    use super::*;"
+    echo
+    body -m32
+    echo "}"
+} > "$TMP/layout.rs"
+
+if [ "${1:-}" = "--check" ]; then
+    if ! diff -u "$OUT" "$TMP/layout.rs"; then
+        echo "$OUT is out of date with $HEADER ($SHA), rerun data/seq_layout.sh" >&2
+        exit 1
+    fi
+else
+    cp "$TMP/layout.rs" "$OUT"
+fi
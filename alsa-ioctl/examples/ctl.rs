@@ -0,0 +1,39 @@
+use rustix::fs::{Mode, OFlags};
+
+use alsa_ioctl::{ctl_ioctl, rawmidi_ioctl};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    for card in 0..32 {
+        let path = format!("/dev/snd/controlC{card}");
+        let Ok(fd) = rustix::fs::open(path, OFlags::RDONLY, Mode::empty()) else {
+            continue;
+        };
+
+        let info = ctl_ioctl::card_info(&fd)?;
+        println!(
+            "card {}: {} [{}] {}",
+            info.card, info.id, info.driver, info.longname
+        );
+
+        let mut device = -1;
+        loop {
+            ctl_ioctl::rawmidi_next_device(&fd, &mut device)?;
+            if device < 0 {
+                break;
+            }
+
+            let mut info: rawmidi_ioctl::RawmidiInfo = unsafe { std::mem::zeroed() };
+            info.device = device as u32;
+            info.stream = rawmidi_ioctl::RawmidiStream::INPUT;
+
+            if ctl_ioctl::rawmidi_info(&fd, &mut info).is_err() {
+                info.stream = rawmidi_ioctl::RawmidiStream::OUTPUT;
+                ctl_ioctl::rawmidi_info(&fd, &mut info)?;
+            }
+
+            println!("  midiC{}D{}: {} {:?}", card, device, info.name, info.flags);
+        }
+    }
+
+    Ok(())
+}
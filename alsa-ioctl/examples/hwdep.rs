@@ -0,0 +1,50 @@
+use rustix::fs::{Mode, OFlags};
+
+use alsa_ioctl::{ctl_ioctl, hwdep_ioctl};
+
+/// Lists hwdep devices, optionally loading a DSP image: `hwdep <card> <device> <image>`
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    for card in 0..32 {
+        let path = format!("/dev/snd/controlC{card}");
+        let Ok(ctl) = rustix::fs::open(path, OFlags::RDONLY, Mode::empty()) else {
+            continue;
+        };
+
+        let mut device = -1;
+        loop {
+            ctl_ioctl::hwdep_next_device(&ctl, &mut device)?;
+            if device < 0 {
+                break;
+            }
+
+            let mut info: hwdep_ioctl::HwdepInfo = unsafe { std::mem::zeroed() };
+            info.device = device as u32;
+            ctl_ioctl::hwdep_info(&ctl, &mut info)?;
+            println!("hwC{}D{}: {} {:?}", card, device, info.name, info.iface);
+        }
+    }
+
+    if let [card, device, image] = &args[..] {
+        let path = format!("/dev/snd/hwC{card}D{device}");
+        let fd = rustix::fs::open(path, OFlags::RDWR, Mode::empty())?;
+
+        let version = hwdep_ioctl::pversion(&fd)?;
+        dbg!(version);
+
+        let status = hwdep_ioctl::dsp_status(&fd)?;
+        dbg!(status);
+
+        let data = std::fs::read(image)?;
+        for index in 0..status.num_dsps {
+            if !status.is_loaded(index) {
+                hwdep_ioctl::load_dsp(&fd, index, image, &data, 0)?;
+                println!("loaded {} as DSP {}", image, index);
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
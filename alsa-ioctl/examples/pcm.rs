@@ -0,0 +1,71 @@
+use rustix::fs::{Mode, OFlags};
+
+use alsa_ioctl::pcm_ioctl::{self, HwParam, HwParams, Interval, PcmAccess, PcmFormat, XferI};
+
+const CHANNELS: u32 = 2;
+const RATE: u32 = 48000;
+
+/// Plays one second of a 440Hz sine
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "/dev/snd/pcmC0D0p".into());
+    let fd = rustix::fs::open(path, OFlags::RDWR, Mode::empty())?;
+
+    let version = pcm_ioctl::pversion(&fd)?;
+    dbg!(version);
+
+    let info = pcm_ioctl::info(&fd)?;
+    dbg!(info.name, info.subname, info.stream);
+
+    let mut params = HwParams::any();
+    pcm_ioctl::hw_refine(&fd, &mut params)?;
+    let formats: Vec<_> = params.mask(HwParam::FORMAT).iter().collect();
+    dbg!(formats, params.interval(HwParam::RATE));
+
+    params.set_access(PcmAccess::RW_INTERLEAVED);
+    params.set_format(PcmFormat::S16);
+    params.set_interval(HwParam::CHANNELS, Interval::single(CHANNELS));
+    params.set_interval(HwParam::RATE, Interval::single(RATE));
+    pcm_ioctl::hw_params(&fd, &mut params)?;
+    dbg!(
+        params.interval(HwParam::PERIOD_SIZE).value(),
+        params.interval(HwParam::BUFFER_SIZE).value()
+    );
+
+    let mut sw_params: pcm_ioctl::SwParams = unsafe { std::mem::zeroed() };
+    sw_params.avail_min = params.interval(HwParam::PERIOD_SIZE).min as _;
+    sw_params.start_threshold = params.interval(HwParam::BUFFER_SIZE).min as _;
+    sw_params.stop_threshold = sw_params.start_threshold;
+    sw_params.boundary = sw_params.start_threshold;
+    while sw_params.boundary * 2 <= (std::os::raw::c_long::MAX as _) {
+        sw_params.boundary *= 2;
+    }
+    pcm_ioctl::sw_params(&fd, &mut sw_params)?;
+
+    pcm_ioctl::prepare(&fd)?;
+
+    let mut samples: Vec<i16> = (0..RATE)
+        .flat_map(|n| {
+            let t = n as f32 / RATE as f32;
+            let s = (t * 440.0 * std::f32::consts::TAU).sin() * i16::MAX as f32 * 0.25;
+            [s as i16; CHANNELS as usize]
+        })
+        .collect();
+
+    let mut xfer = XferI {
+        result: 0,
+        buf: samples.as_mut_ptr().cast(),
+        frames: RATE as _,
+    };
+    // `samples` holds `RATE` frames of S16 with `CHANNELS` channels
+    unsafe { pcm_ioctl::writei_frames(&fd, &mut xfer)? };
+    dbg!(xfer.result);
+
+    let status = pcm_ioctl::status(&fd)?;
+    dbg!(status.state, status.delay, status.avail);
+
+    pcm_ioctl::drain(&fd)?;
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use rustix::{
+    fs::{Mode, OFlags},
+    io::read,
+};
+
+use alsa_ioctl::rawmidi_ioctl::{self, RawmidiStream};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "/dev/snd/midiC0D0".into());
+    let fd = rustix::fs::open(path, OFlags::RDONLY, Mode::empty())?;
+
+    let version = rawmidi_ioctl::pversion(&fd)?;
+    dbg!(version);
+
+    let info = rawmidi_ioctl::info(&fd)?;
+    dbg!(info.name, info.subname, info.flags);
+
+    let mut status: rawmidi_ioctl::RawmidiStatus = unsafe { std::mem::zeroed() };
+    status.stream = RawmidiStream::INPUT;
+    rawmidi_ioctl::status(&fd, &mut status)?;
+    dbg!(status.avail, status.xruns);
+
+    let mut buff = [0u8; 256];
+
+    loop {
+        let len = read(&fd, &mut buff)?;
+        println!("{:02x?}", &buff[..len]);
+    }
+}
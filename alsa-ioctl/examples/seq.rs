@@ -15,10 +15,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let client_id = seq_ioctl::client_id(&fd)?;
     dbg!(client_id);
 
-    let mut port: seq_ioctl::PortInfo = unsafe { std::mem::zeroed() };
-    port.addr.client = client_id.0 as u8;
-    port.capability = PortCapability::WRITE | PortCapability::SUBS_WRITE;
-    port.type_ = PortType::MIDI_GENERIC | PortType::APPLICATION;
+    let mut port = seq_ioctl::PortInfo::new(seq_ioctl::Addr::new(client_id.0 as u8, 0))
+        .with_name("ralsa example")
+        .with_capability(PortCapability::WRITE | PortCapability::SUBS_WRITE)
+        .with_type(PortType::MIDI_GENERIC | PortType::APPLICATION);
 
     seq_ioctl::create_port(&fd, &mut port)?;
 
@@ -0,0 +1,29 @@
+use rustix::fs::{Mode, OFlags};
+
+use alsa_ioctl::timer_ioctl::{self, SndTimerId};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let fd = rustix::fs::open("/dev/snd/timer", OFlags::RDONLY, Mode::empty())?;
+
+    let version = timer_ioctl::pversion(&fd)?;
+    dbg!(version);
+
+    let mut id = SndTimerId::NONE;
+    loop {
+        timer_ioctl::next_device(&fd, &mut id)?;
+        if id.is_none() {
+            break;
+        }
+
+        let mut info: timer_ioctl::TimerGInfo = unsafe { std::mem::zeroed() };
+        info.tid = id;
+        timer_ioctl::ginfo(&fd, &mut info)?;
+
+        println!(
+            "{:?}: {} resolution {}ns ({}..{}ns)",
+            id, info.name, info.resolution, info.resolution_min, info.resolution_max
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use std::os::{fd::AsFd, raw::c_int};
+
+use rustix::{
+    io::Result,
+    ioctl::{ioctl, ReadOpcode, ReadWriteOpcode, Updater},
+};
+
+use crate::{hwdep_ioctl::HwdepInfo, rawmidi_ioctl::RawmidiInfo, Version};
+
+use super::types;
+
+// #define SNDRV_CTL_IOCTL_PVERSION	_IOR('U', 0x00, int)
+ioctl_read! {
+    pversion, b'U', 0x00, Version
+}
+
+// #define SNDRV_CTL_IOCTL_CARD_INFO	_IOR('U', 0x01, struct snd_ctl_card_info)
+ioctl_read! {
+    card_info, b'U', 0x01, types::CardInfo
+}
+
+//
+// Elements
+//
+
+// #define SNDRV_CTL_IOCTL_ELEM_LIST	_IOWR('U', 0x10, struct snd_ctl_elem_list)
+ioctl_readwrite! {
+    elem_list, b'U', 0x10, types::ElemList
+}
+
+// #define SNDRV_CTL_IOCTL_ELEM_INFO	_IOWR('U', 0x11, struct snd_ctl_elem_info)
+ioctl_readwrite! {
+    elem_info, b'U', 0x11, types::ElemInfo
+}
+
+// #define SNDRV_CTL_IOCTL_ELEM_READ	_IOWR('U', 0x12, struct snd_ctl_elem_value)
+ioctl_readwrite! {
+    elem_read, b'U', 0x12, types::ElemValue
+}
+
+// #define SNDRV_CTL_IOCTL_ELEM_WRITE	_IOWR('U', 0x13, struct snd_ctl_elem_value)
+ioctl_readwrite! {
+    elem_write, b'U', 0x13, types::ElemValue
+}
+
+// #define SNDRV_CTL_IOCTL_SUBSCRIBE_EVENTS _IOWR('U', 0x16, int)
+ioctl_readwrite! {
+    subscribe_events, b'U', 0x16, c_int
+}
+
+//
+// TLV
+//
+// `struct snd_ctl_tlv` ends with a flexible array, the opcode is encoded with the
+// size of the header only while the kernel reads/writes `length` bytes past it.
+//
+
+// #define SNDRV_CTL_IOCTL_TLV_READ	_IOWR('U', 0x1a, struct snd_ctl_tlv)
+pub fn tlv_read<const N: usize>(fd: impl AsFd, data: &mut types::Tlv<N>) -> Result<()> {
+    type Opcode = ReadWriteOpcode<b'U', 0x1a, types::Tlv<0>>;
+    unsafe { ioctl(fd, Updater::<Opcode, types::Tlv<N>>::new(data)) }
+}
+
+// #define SNDRV_CTL_IOCTL_TLV_WRITE	_IOWR('U', 0x1b, struct snd_ctl_tlv)
+pub fn tlv_write<const N: usize>(fd: impl AsFd, data: &mut types::Tlv<N>) -> Result<()> {
+    type Opcode = ReadWriteOpcode<b'U', 0x1b, types::Tlv<0>>;
+    unsafe { ioctl(fd, Updater::<Opcode, types::Tlv<N>>::new(data)) }
+}
+
+// #define SNDRV_CTL_IOCTL_TLV_COMMAND	_IOWR('U', 0x1c, struct snd_ctl_tlv)
+pub fn tlv_command<const N: usize>(fd: impl AsFd, data: &mut types::Tlv<N>) -> Result<()> {
+    type Opcode = ReadWriteOpcode<b'U', 0x1c, types::Tlv<0>>;
+    unsafe { ioctl(fd, Updater::<Opcode, types::Tlv<N>>::new(data)) }
+}
+
+//
+// Hwdep
+//
+
+// #define SNDRV_CTL_IOCTL_HWDEP_NEXT_DEVICE _IOWR('U', 0x20, int)
+ioctl_readwrite! {
+    hwdep_next_device, b'U', 0x20, c_int
+}
+
+// #define SNDRV_CTL_IOCTL_HWDEP_INFO	_IOR('U', 0x21, struct snd_hwdep_info)
+// Declared as read only, but the kernel reads `device` first.
+pub fn hwdep_info(fd: impl AsFd, data: &mut HwdepInfo) -> Result<()> {
+    type Opcode = ReadOpcode<b'U', 0x21, HwdepInfo>;
+    unsafe { ioctl(fd, Updater::<Opcode, HwdepInfo>::new(data)) }
+}
+
+//
+// Rawmidi
+//
+
+// #define SNDRV_CTL_IOCTL_RAWMIDI_NEXT_DEVICE _IOWR('U', 0x40, int)
+ioctl_readwrite! {
+    rawmidi_next_device, b'U', 0x40, c_int
+}
+
+// #define SNDRV_CTL_IOCTL_RAWMIDI_INFO	_IOWR('U', 0x41, struct snd_rawmidi_info)
+ioctl_readwrite! {
+    rawmidi_info, b'U', 0x41, RawmidiInfo
+}
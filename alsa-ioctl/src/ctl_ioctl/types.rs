@@ -0,0 +1,319 @@
+use super::super::{bitfield_unit::BitfieldUnit, string::AsciiString};
+use bitflags::bitflags;
+use std::os::raw::{c_int, c_long, c_longlong, c_uchar, c_uint};
+
+/// Digital audio interface
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AesIec958 {
+    /// AES/IEC958 channel status bits
+    pub status: [c_uchar; 24usize],
+    /// AES/IEC958 subcode bits
+    pub subcode: [c_uchar; 147usize],
+    /// nothing
+    pad: c_uchar,
+    /// AES/IEC958 subframe bits
+    pub dig_subframe: [c_uchar; 4usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CardInfo {
+    /// card number
+    pub card: c_int,
+    /// reserved for future (was type)
+    pad: c_int,
+    /// ID of card (user selectable)
+    pub id: AsciiString<16>,
+    /// Driver name
+    pub driver: AsciiString<16>,
+    /// Short name of soundcard
+    pub name: AsciiString<32>,
+    /// name + info text about soundcard
+    pub longname: AsciiString<80>,
+    /// reserved for future (was ID of mixer)
+    reserved_: [c_uchar; 16usize],
+    /// visual mixer identification
+    pub mixername: AsciiString<80>,
+    /// card components / fine identification, delimited with one space (AC97 etc..)
+    pub components: AsciiString<128>,
+}
+
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ElemType(pub c_int);
+
+impl ElemType {
+    /// invalid
+    pub const NONE: Self = Self(0);
+    /// boolean type
+    pub const BOOLEAN: Self = Self(1);
+    /// integer type
+    pub const INTEGER: Self = Self(2);
+    /// enumerated type
+    pub const ENUMERATED: Self = Self(3);
+    /// byte array
+    pub const BYTES: Self = Self(4);
+    /// IEC958 (S/PDIF) setup
+    pub const IEC958: Self = Self(5);
+    /// 64-bit integer type
+    pub const INTEGER64: Self = Self(6);
+}
+
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ElemIface(pub c_int);
+
+impl ElemIface {
+    /// global control
+    pub const CARD: Self = Self(0);
+    /// hardware dependent device
+    pub const HWDEP: Self = Self(1);
+    /// virtual mixer device
+    pub const MIXER: Self = Self(2);
+    /// PCM device
+    pub const PCM: Self = Self(3);
+    /// RawMidi device
+    pub const RAWMIDI: Self = Self(4);
+    /// timer device
+    pub const TIMER: Self = Self(5);
+    /// sequencer client
+    pub const SEQUENCER: Self = Self(6);
+}
+
+bitflags! {
+    /// Used in `ElemInfo::access`
+    #[repr(transparent)]
+    pub struct ElemAccess: c_uint {
+        const READ = 1<<0;
+        const WRITE = 1<<1;
+        const READWRITE = Self::READ.bits | Self::WRITE.bits;
+        /// control value may be changed without a notification
+        const VOLATILE = 1<<2;
+        /// TLV read is possible
+        const TLV_READ = 1<<4;
+        /// TLV write is possible
+        const TLV_WRITE = 1<<5;
+        const TLV_READWRITE = Self::TLV_READ.bits | Self::TLV_WRITE.bits;
+        /// TLV command is possible
+        const TLV_COMMAND = 1<<6;
+        /// control does actually nothing, but may be updated
+        const INACTIVE = 1<<8;
+        /// write lock
+        const LOCK = 1<<9;
+        /// write lock owner
+        const OWNER = 1<<10;
+        /// kernel use a TLV callback
+        const TLV_CALLBACK = 1<<28;
+        /// user space element
+        const USER = 1<<29;
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ElemId {
+    /// numeric identifier, zero = invalid
+    pub numid: c_uint,
+    /// interface identifier
+    pub iface: ElemIface, // c_int
+    /// device/client number
+    pub device: c_uint,
+    /// subdevice (substream) number
+    pub subdevice: c_uint,
+    /// ASCII name of item
+    pub name: AsciiString<44>,
+    /// index of item
+    pub index: c_uint,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ElemList {
+    /// W: first element ID to get
+    pub offset: c_uint,
+    /// W: count of element IDs to get
+    pub space: c_uint,
+    /// R: count of element IDs set
+    pub used: c_uint,
+    /// R: count of all elements
+    pub count: c_uint,
+    /// R: IDs
+    pub pids: *mut ElemId,
+    reserved: [c_uchar; 50usize],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ElemInfo {
+    /// W: element ID
+    pub id: ElemId,
+    /// R: value type - SNDRV_CTL_ELEM_TYPE_*
+    pub type_: ElemType, // c_int
+    /// R: value access (bitmask) - SNDRV_CTL_ELEM_ACCESS_*
+    pub access: ElemAccess, // c_uint
+    /// count of values
+    pub count: c_uint,
+    /// owner's PID of this control
+    pub owner: c_int,
+    pub value: ElemInfoValue,
+    reserved: [c_uchar; 64usize],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union ElemInfoValue {
+    pub integer: ElemInfoInteger,
+    pub integer64: ElemInfoInteger64,
+    pub enumerated: ElemInfoEnumerated,
+    reserved: [c_uchar; 128usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ElemInfoInteger {
+    /// R: minimum value
+    pub min: c_long,
+    /// R: maximum value
+    pub max: c_long,
+    /// R: step (0 variable)
+    pub step: c_long,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ElemInfoInteger64 {
+    /// R: minimum value
+    pub min: c_longlong,
+    /// R: maximum value
+    pub max: c_longlong,
+    /// R: step (0 variable)
+    pub step: c_longlong,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ElemInfoEnumerated {
+    /// R: number of items
+    pub items: c_uint,
+    /// W: item number
+    pub item: c_uint,
+    /// R: value name
+    pub name: AsciiString<64>,
+    /// W: names list (ELEM_ADD only)
+    pub names_ptr: u64,
+    pub names_length: c_uint,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ElemValue {
+    /// W: element ID
+    pub id: ElemId,
+    _bitfield_align_1: [u8; 0],
+    /// W: indirect access - obsoleted
+    _bitfield_1: BitfieldUnit<[u8; 1usize]>,
+    /// RO
+    pub value: ElemValueData,
+    reserved: [c_uchar; 128usize],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union ElemValueData {
+    pub integer: [c_long; 128usize],
+    pub integer64: [c_longlong; 64usize],
+    pub enumerated: [c_uint; 128usize],
+    pub bytes: [c_uchar; 512usize],
+    pub iec958: AesIec958,
+}
+
+/// TLV (type-length-value) container
+///
+/// `N` is the capacity of `tlv` in 32 bit words.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Tlv<const N: usize> {
+    /// control element numeric identification
+    pub numid: c_uint,
+    /// in bytes aligned to 4
+    pub length: c_uint,
+    /// first TLV
+    pub tlv: [c_uint; N],
+}
+
+impl<const N: usize> Tlv<N> {
+    /// Empty TLV with `length` set to the whole capacity
+    pub fn new(numid: c_uint) -> Self {
+        Self {
+            numid,
+            length: (N * std::mem::size_of::<c_uint>()) as c_uint,
+            tlv: [0; N],
+        }
+    }
+
+    /// Words filled by the kernel
+    pub fn data(&self) -> &[c_uint] {
+        let len = self.length as usize / std::mem::size_of::<c_uint>();
+        &self.tlv[..len.min(N)]
+    }
+}
+
+//
+// Read interface.
+//
+
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CtlEventType(pub c_int);
+
+impl CtlEventType {
+    pub const ELEM: Self = Self(0);
+}
+
+bitflags! {
+    /// Used in `CtlEventElem::mask`
+    #[repr(transparent)]
+    pub struct CtlEventMask: c_uint {
+        /// element value was changed
+        const VALUE = 1<<0;
+        /// element info was changed
+        const INFO = 1<<1;
+        /// element was added
+        const ADD = 1<<2;
+        /// element TLV tree was changed
+        const TLV = 1<<3;
+    }
+}
+
+impl CtlEventMask {
+    /// element was removed
+    pub const REMOVE: Self = Self { bits: !0 };
+
+    pub fn is_remove(&self) -> bool {
+        *self == Self::REMOVE
+    }
+}
+
+/// Event read from the control device after `subscribe_events`
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CtlEvent {
+    /// event type - SNDRV_CTL_EVENT_*
+    pub type_: CtlEventType, // c_int
+    pub data: CtlEventData,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union CtlEventData {
+    pub elem: CtlEventElem,
+    pub data8: [c_uchar; 60usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CtlEventElem {
+    pub mask: CtlEventMask, // c_uint
+    pub id: ElemId,
+}
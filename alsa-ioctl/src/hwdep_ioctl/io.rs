@@ -0,0 +1,47 @@
+use std::os::{
+    fd::AsFd,
+    raw::{c_uint, c_ulong},
+};
+
+use rustix::io::Result;
+
+use super::{ioctl::dsp_load, types::DspImage};
+use crate::string::AsciiString;
+
+/// Read from the device
+///
+/// The data format is driver specific, drivers without read support fail with `ENXIO`.
+pub fn read(fd: impl AsFd, buff: &mut [u8]) -> Result<usize> {
+    rustix::io::read(fd, buff)
+}
+
+/// Write to the device
+///
+/// The data format is driver specific, drivers without write support fail with `ENXIO`.
+pub fn write(fd: impl AsFd, buff: &[u8]) -> Result<usize> {
+    rustix::io::write(fd, buff)
+}
+
+/// Transfer the DSP image `index` to the device
+///
+/// `name` is truncated to 63 bytes. Check `dsp_status` for the number of images the
+/// driver expects and the ones already loaded.
+pub fn load_dsp(
+    fd: impl AsFd,
+    index: c_uint,
+    name: &str,
+    image: &[u8],
+    driver_data: c_ulong,
+) -> Result<()> {
+    let image = DspImage {
+        index,
+        name: AsciiString::new(name),
+        // the kernel only reads from it
+        image: image.as_ptr() as *mut u8,
+        length: image.len(),
+        driver_data,
+    };
+
+    // `image` points to `length` bytes borrowed for the whole call
+    unsafe { dsp_load(fd, image) }
+}
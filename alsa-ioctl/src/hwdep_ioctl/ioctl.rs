@@ -0,0 +1,35 @@
+use std::os::fd::AsFd;
+
+use rustix::{
+    io::Result,
+    ioctl::{ioctl, Setter, WriteOpcode},
+};
+
+use crate::Version;
+
+use super::types;
+
+// #define SNDRV_HWDEP_IOCTL_PVERSION	_IOR ('H', 0x00, int)
+ioctl_read! {
+    pversion, b'H', 0x00, Version
+}
+
+// #define SNDRV_HWDEP_IOCTL_INFO		_IOR ('H', 0x01, struct snd_hwdep_info)
+ioctl_read! {
+    info, b'H', 0x01, types::HwdepInfo
+}
+
+// #define SNDRV_HWDEP_IOCTL_DSP_STATUS	_IOR('H', 0x02, struct snd_hwdep_dsp_status)
+ioctl_read! {
+    dsp_status, b'H', 0x02, types::DspStatus
+}
+
+// #define SNDRV_HWDEP_IOCTL_DSP_LOAD	_IOW('H', 0x03, struct snd_hwdep_dsp_image)
+/// See `load_dsp` for a safe version
+///
+/// # Safety
+/// `image.image` must point to `image.length` readable bytes.
+pub unsafe fn dsp_load(fd: impl AsFd, image: types::DspImage) -> Result<()> {
+    type Opcode = WriteOpcode<b'H', 0x03, types::DspImage>;
+    ioctl(fd, Setter::<Opcode, types::DspImage>::new(image))
+}
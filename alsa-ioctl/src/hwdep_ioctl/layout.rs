@@ -0,0 +1,53 @@
+//! Layout of the ioctl structs as compiled from `data/asound.h` with gcc
+//! (`-m64` and `-m32`), checked at compile time.
+
+use super::types::*;
+
+assert_layout!(
+    HwdepInfo,
+    size = 220,
+    align = 4,
+    card = 4,
+    id = 8,
+    name = 72,
+    iface = 152,
+);
+assert_layout!(
+    DspStatus,
+    size = 64,
+    align = 4,
+    id = 4,
+    num_dsps = 36,
+    dsp_loaded = 40,
+    chip_ready = 44,
+);
+
+#[cfg(target_pointer_width = "64")]
+mod lp64 {
+    use super::*;
+
+    assert_layout!(
+        DspImage,
+        size = 96,
+        align = 8,
+        name = 4,
+        image = 72,
+        length = 80,
+        driver_data = 88,
+    );
+}
+
+#[cfg(target_pointer_width = "32")]
+mod ilp32 {
+    use super::*;
+
+    assert_layout!(
+        DspImage,
+        size = 80,
+        align = 4,
+        name = 4,
+        image = 68,
+        length = 72,
+        driver_data = 76,
+    );
+}
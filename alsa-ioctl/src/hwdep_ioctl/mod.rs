@@ -0,0 +1,8 @@
+mod io;
+mod ioctl;
+mod layout;
+mod types;
+
+pub use io::*;
+pub use ioctl::*;
+pub use types::*;
@@ -0,0 +1,120 @@
+use super::super::string::AsciiString;
+use std::os::raw::{c_int, c_uchar, c_uint, c_ulong};
+
+/// Used in `HwdepInfo::iface`
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HwdepIface(pub c_int);
+
+impl HwdepIface {
+    pub const OPL2: Self = Self(0);
+    pub const OPL3: Self = Self(1);
+    pub const OPL4: Self = Self(2);
+    /// Creative Signal Processor
+    pub const SB16CSP: Self = Self(3);
+    /// FX8010 processor in EMU10K1 chip
+    pub const EMU10K1: Self = Self(4);
+    /// Yamaha FX processor
+    pub const YSS225: Self = Self(5);
+    /// Wavetable synth
+    pub const ICS2115: Self = Self(6);
+    /// Ensoniq SoundScape ISA card (MC68EC000)
+    pub const SSCAPE: Self = Self(7);
+    /// Digigram VX cards
+    pub const VX: Self = Self(8);
+    /// Digigram miXart cards
+    pub const MIXART: Self = Self(9);
+    /// Tascam US122, US224 & US428 usb
+    pub const USX2Y: Self = Self(10);
+    /// EmuX wavetable
+    pub const EMUX_WAVETABLE: Self = Self(11);
+    /// Bluetooth audio
+    pub const BLUETOOTH: Self = Self(12);
+    /// Tascam US122, US224 & US428 rawusb pcm
+    pub const USX2Y_PCM: Self = Self(13);
+    /// Digigram PCXHR
+    pub const PCXHR: Self = Self(14);
+    /// SB Extigy/Audigy2NX remote control
+    pub const SB_RC: Self = Self(15);
+    /// HD-audio
+    pub const HDA: Self = Self(16);
+    /// direct access to usb stream
+    pub const USB_STREAM: Self = Self(17);
+    /// TC DICE FireWire device
+    pub const FW_DICE: Self = Self(18);
+    /// Echo Audio Fireworks based device
+    pub const FW_FIREWORKS: Self = Self(19);
+    /// BridgeCo BeBoB based device
+    pub const FW_BEBOB: Self = Self(20);
+    /// Oxford OXFW970/971 based device
+    pub const FW_OXFW: Self = Self(21);
+    /// Digidesign Digi 002/003 family
+    pub const FW_DIGI00X: Self = Self(22);
+    /// TASCAM FireWire series
+    pub const FW_TASCAM: Self = Self(23);
+    /// Line6 USB processors
+    pub const LINE6: Self = Self(24);
+    /// MOTU FireWire series
+    pub const FW_MOTU: Self = Self(25);
+    /// RME Fireface series
+    pub const FW_FIREFACE: Self = Self(26);
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct HwdepInfo {
+    /// WR: device number
+    pub device: c_uint,
+    /// R: card number
+    pub card: c_int,
+    /// ID (user selectable)
+    pub id: AsciiString<64>,
+    /// hwdep name
+    pub name: AsciiString<80>,
+    /// hwdep interface
+    pub iface: HwdepIface, // c_int
+    reserved: [c_uchar; 64usize],
+}
+
+/// generic DSP loader
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DspStatus {
+    /// R: driver-specific version
+    pub version: c_uint,
+    /// R: driver-specific ID string
+    pub id: AsciiString<32>,
+    /// R: number of DSP images to transfer
+    pub num_dsps: c_uint,
+    /// R: bit flags indicating the loaded DSPs
+    pub dsp_loaded: c_uint,
+    /// R: 1 = initialization finished
+    pub chip_ready: c_uint,
+    reserved: [c_uchar; 16usize],
+}
+
+impl DspStatus {
+    /// Is the DSP image `index` loaded
+    pub fn is_loaded(&self, index: c_uint) -> bool {
+        index < c_uint::BITS && self.dsp_loaded & (1 << index) != 0
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.chip_ready != 0
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DspImage {
+    /// W: DSP index
+    pub index: c_uint,
+    /// W: ID (e.g. file name)
+    pub name: AsciiString<64>,
+    /// W: binary image
+    pub image: *mut c_uchar,
+    /// W: size of image in bytes
+    pub length: usize,
+    /// W: driver-specific data
+    pub driver_data: c_ulong,
+}
@@ -1,6 +1,15 @@
+#[macro_use]
+mod macros;
+
 pub mod bitfield_unit;
+pub mod ctl_ioctl;
+pub mod hwdep_ioctl;
+pub mod pcm_ioctl;
+pub mod rawmidi_ioctl;
 pub mod seq_ioctl;
 pub mod string;
+pub mod timer_ioctl;
+pub mod timespec;
 pub mod version;
 
 pub use version::Version;
@@ -0,0 +1,164 @@
+macro_rules! ioctl_readwrite {
+    ($name:ident, $ioty:expr, $nr:expr, $ty:ty) => {
+        pub fn $name(fd: impl ::std::os::fd::AsFd, data: &mut $ty) -> ::rustix::io::Result<()> {
+            type Opcode = ::rustix::ioctl::ReadWriteOpcode<$ioty, $nr, $ty>;
+            unsafe {
+                ::rustix::ioctl::ioctl(fd, ::rustix::ioctl::Updater::<Opcode, $ty>::new(data))
+            }
+        }
+    };
+}
+
+macro_rules! ioctl_read {
+    ($name:ident, $ioty:expr, $nr:expr, $ty:ty) => {
+        pub fn $name(fd: impl ::std::os::fd::AsFd) -> ::rustix::io::Result<$ty> {
+            type Opcode = ::rustix::ioctl::ReadOpcode<$ioty, $nr, $ty>;
+            unsafe { ::rustix::ioctl::ioctl(fd, ::rustix::ioctl::Getter::<Opcode, $ty>::new()) }
+        }
+    };
+}
+
+macro_rules! ioctl_write_ptr {
+    ($name:ident, $ioty:expr, $nr:expr, $ty:ty) => {
+        pub fn $name(fd: impl ::std::os::fd::AsFd, data: $ty) -> ::rustix::io::Result<()> {
+            type Opcode = ::rustix::ioctl::WriteOpcode<$ioty, $nr, $ty>;
+            unsafe { ::rustix::ioctl::ioctl(fd, ::rustix::ioctl::Setter::<Opcode, $ty>::new(data)) }
+        }
+    };
+}
+
+macro_rules! ioctl_none {
+    ($name:ident, $ioty:expr, $nr:expr) => {
+        pub fn $name(fd: impl ::std::os::fd::AsFd) -> ::rustix::io::Result<()> {
+            type Opcode = ::rustix::ioctl::NoneOpcode<$ioty, $nr, ()>;
+            unsafe { ::rustix::ioctl::ioctl(fd, ::rustix::ioctl::NoArg::<Opcode>::new()) }
+        }
+    };
+}
+
+/// Compile time check of a type's size, alignment and field offsets
+macro_rules! assert_layout {
+    ($ty:ty, size = $size:expr, align = $align:expr $(, $field:ident = $offset:expr)* $(,)?) => {
+        const _: () = {
+            assert!(::std::mem::size_of::<$ty>() == $size);
+            assert!(::std::mem::align_of::<$ty>() == $align);
+            $(assert!(::std::mem::offset_of!($ty, $field) == $offset);)*
+        };
+    };
+}
+
+/// Serde for bitflags as a list of flag names
+///
+/// Bits without a name are kept as one `"0x..."` hex string so nothing is lost.
+#[cfg(feature = "serde")]
+macro_rules! serde_bitflags {
+    ($ty:ident: $bits:ty { $($flag:ident),* $(,)? }) => {
+        impl $ty {
+            const SERDE_NAMES: &'static [(&'static str, $ty)] = &[$((stringify!($flag), $ty::$flag)),*];
+        }
+
+        impl ::serde::Serialize for $ty {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use ::serde::ser::SerializeSeq;
+
+                let mut rest = *self;
+                let mut seq = serializer.serialize_seq(None)?;
+                for (name, flag) in Self::SERDE_NAMES {
+                    if self.contains(*flag) {
+                        seq.serialize_element(name)?;
+                        rest.remove(*flag);
+                    }
+                }
+                if !rest.is_empty() {
+                    seq.serialize_element(&format!("{:#x}", rest.bits()))?;
+                }
+                seq.end()
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $ty {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let names = <Vec<String> as ::serde::Deserialize>::deserialize(deserializer)?;
+                let mut flags = Self::empty();
+                for name in names {
+                    if let Some((_, flag)) = Self::SERDE_NAMES.iter().find(|(n, _)| *n == name) {
+                        flags |= *flag;
+                    } else if let Some(hex) = name.strip_prefix("0x") {
+                        let bits = <$bits>::from_str_radix(hex, 16)
+                            .map_err(::serde::de::Error::custom)?;
+                        // unnamed bits are kept as is, like the kernel does
+                        flags = unsafe { Self::from_bits_unchecked(flags.bits() | bits) };
+                    } else {
+                        return Err(::serde::de::Error::custom(format!(
+                            "unknown {} flag `{}`",
+                            stringify!($ty),
+                            name
+                        )));
+                    }
+                }
+                Ok(flags)
+            }
+        }
+    };
+}
+
+/// Serde for a `#[repr(transparent)]` newtype as its raw value
+#[cfg(feature = "serde")]
+macro_rules! serde_newtype {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl ::serde::Serialize for $ty {
+                fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    ::serde::Serialize::serialize(&self.0, serializer)
+                }
+            }
+
+            impl<'de> ::serde::Deserialize<'de> for $ty {
+                fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    ::serde::Deserialize::deserialize(deserializer).map(Self)
+                }
+            }
+        )*
+    };
+}
+
+/// Serde for the public fields of an ioctl struct
+///
+/// Missing fields are left at `Default`, private and reserved fields are never
+/// written or read.
+#[cfg(feature = "serde")]
+macro_rules! serde_struct {
+    ($ty:ident { $($name:literal => $field:ident: $fty:ty),* $(,)? }) => {
+        impl ::serde::Serialize for $ty {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use ::serde::ser::SerializeStruct;
+
+                let len = [$($name),*].len();
+                let mut state = serializer.serialize_struct(stringify!($ty), len)?;
+                $(state.serialize_field($name, &self.$field)?;)*
+                state.end()
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $ty {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                #[derive(::serde::Deserialize)]
+                struct Fields {
+                    $(
+                        #[serde(rename = $name, default)]
+                        $field: Option<$fty>,
+                    )*
+                }
+
+                let fields: Fields = ::serde::Deserialize::deserialize(deserializer)?;
+                let mut this = Self::default();
+                $(
+                    if let Some(value) = fields.$field {
+                        this.$field = value;
+                    }
+                )*
+                Ok(this)
+            }
+        }
+    };
+}
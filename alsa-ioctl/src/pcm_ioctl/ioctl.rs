@@ -0,0 +1,103 @@
+use std::os::{fd::AsFd, raw::c_int};
+
+use rustix::ioctl::{ReadOpcode, Updater, WriteOpcode};
+
+use crate::Version;
+
+use super::types;
+
+// #define SNDRV_PCM_IOCTL_PVERSION	_IOR('A', 0x00, int)
+ioctl_read! {
+    pversion, b'A', 0x00, Version
+}
+
+// #define SNDRV_PCM_IOCTL_INFO		_IOR('A', 0x01, struct snd_pcm_info)
+ioctl_read! {
+    info, b'A', 0x01, types::PcmInfo
+}
+
+// #define SNDRV_PCM_IOCTL_USER_PVERSION	_IOW('A', 0x04, int)
+ioctl_write_ptr! {
+    user_pversion, b'A', 0x04, Version
+}
+
+// #define SNDRV_PCM_IOCTL_HW_REFINE	_IOWR('A', 0x10, struct snd_pcm_hw_params)
+ioctl_readwrite! {
+    hw_refine, b'A', 0x10, types::HwParams
+}
+
+// #define SNDRV_PCM_IOCTL_HW_PARAMS	_IOWR('A', 0x11, struct snd_pcm_hw_params)
+ioctl_readwrite! {
+    hw_params, b'A', 0x11, types::HwParams
+}
+
+// #define SNDRV_PCM_IOCTL_HW_FREE		_IO('A', 0x12)
+ioctl_none! {
+    hw_free, b'A', 0x12
+}
+
+// #define SNDRV_PCM_IOCTL_SW_PARAMS	_IOWR('A', 0x13, struct snd_pcm_sw_params)
+ioctl_readwrite! {
+    sw_params, b'A', 0x13, types::SwParams
+}
+
+// #define SNDRV_PCM_IOCTL_STATUS		_IOR('A', 0x20, struct snd_pcm_status)
+ioctl_read! {
+    status, b'A', 0x20, types::PcmStatus
+}
+
+// #define SNDRV_PCM_IOCTL_DELAY		_IOR('A', 0x21, snd_pcm_sframes_t)
+ioctl_read! {
+    delay, b'A', 0x21, types::Sframes
+}
+
+// #define SNDRV_PCM_IOCTL_PREPARE		_IO('A', 0x40)
+ioctl_none! {
+    prepare, b'A', 0x40
+}
+
+// #define SNDRV_PCM_IOCTL_RESET		_IO('A', 0x41)
+ioctl_none! {
+    reset, b'A', 0x41
+}
+
+// #define SNDRV_PCM_IOCTL_START		_IO('A', 0x42)
+ioctl_none! {
+    start, b'A', 0x42
+}
+
+// #define SNDRV_PCM_IOCTL_DROP		_IO('A', 0x43)
+ioctl_none! {
+    drop, b'A', 0x43
+}
+
+// #define SNDRV_PCM_IOCTL_DRAIN		_IO('A', 0x44)
+ioctl_none! {
+    drain, b'A', 0x44
+}
+
+// #define SNDRV_PCM_IOCTL_PAUSE		_IOW('A', 0x45, int)
+ioctl_write_ptr! {
+    pause, b'A', 0x45, c_int
+}
+
+// The kernel writes `result` back to both of these despite the _IOW/_IOR
+// direction, so the struct is passed by mutable reference.
+
+// #define SNDRV_PCM_IOCTL_WRITEI_FRAMES	_IOW('A', 0x50, struct snd_xferi)
+/// # Safety
+/// `xfer.buf` must point to at least `xfer.frames` readable frames of the
+/// configured format and channel count.
+pub unsafe fn writei_frames(fd: impl AsFd, xfer: &mut types::XferI) -> rustix::io::Result<()> {
+    type Opcode = WriteOpcode<b'A', 0x50, types::XferI>;
+    rustix::ioctl::ioctl(fd, Updater::<Opcode, types::XferI>::new(xfer))
+}
+
+// #define SNDRV_PCM_IOCTL_READI_FRAMES	_IOR('A', 0x51, struct snd_xferi)
+/// # Safety
+/// `xfer.buf` must point to at least `xfer.frames` writable frames of the
+/// configured format and channel count.
+pub unsafe fn readi_frames(fd: impl AsFd, xfer: &mut types::XferI) -> rustix::io::Result<()> {
+    type Opcode = ReadOpcode<b'A', 0x51, types::XferI>;
+    rustix::ioctl::ioctl(fd, Updater::<Opcode, types::XferI>::new(xfer))
+}
@@ -0,0 +1,106 @@
+//! Layout of the ioctl structs as compiled from `data/asound.h` with gcc
+//! (`-m64` and `-m32`), checked at compile time.
+
+use super::types::*;
+
+assert_layout!(Interval, size = 12, align = 4, min = 0, max = 4);
+assert_layout!(Mask, size = 32, align = 4);
+assert_layout!(PcmSyncId, size = 16, align = 4);
+assert_layout!(
+    PcmInfo,
+    size = 288,
+    align = 4,
+    stream = 8,
+    id = 16,
+    name = 80,
+    subname = 160,
+    dev_class = 192,
+    subdevices_avail = 204,
+    sync = 208,
+);
+
+#[cfg(target_pointer_width = "64")]
+mod lp64 {
+    use super::*;
+
+    assert_layout!(
+        HwParams,
+        size = 608,
+        align = 8,
+        masks = 4,
+        intervals = 260,
+        rmask = 512,
+        info = 520,
+        rate_den = 532,
+        fifo_size = 536,
+    );
+    assert_layout!(
+        SwParams,
+        size = 136,
+        align = 8,
+        sleep_min = 8,
+        avail_min = 16,
+        boundary = 64,
+        proto = 72,
+        tstamp_type = 76,
+    );
+    assert_layout!(
+        PcmStatus,
+        size = 152,
+        align = 8,
+        trigger_tstamp = 8,
+        tstamp = 24,
+        appl_ptr = 40,
+        delay = 56,
+        overrange = 80,
+        suspended_state = 88,
+        audio_tstamp_data = 92,
+        audio_tstamp = 96,
+        driver_tstamp = 112,
+        audio_tstamp_accuracy = 128,
+    );
+    assert_layout!(XferI, size = 24, align = 8, buf = 8, frames = 16);
+}
+
+#[cfg(target_pointer_width = "32")]
+mod ilp32 {
+    use super::*;
+
+    assert_layout!(
+        HwParams,
+        size = 604,
+        align = 4,
+        masks = 4,
+        intervals = 260,
+        rmask = 512,
+        info = 520,
+        rate_den = 532,
+        fifo_size = 536,
+    );
+    assert_layout!(
+        SwParams,
+        size = 104,
+        align = 4,
+        sleep_min = 8,
+        avail_min = 12,
+        boundary = 36,
+        proto = 40,
+        tstamp_type = 44,
+    );
+    assert_layout!(
+        PcmStatus,
+        size = 108,
+        align = 4,
+        trigger_tstamp = 4,
+        tstamp = 12,
+        appl_ptr = 20,
+        delay = 28,
+        overrange = 40,
+        suspended_state = 44,
+        audio_tstamp_data = 48,
+        audio_tstamp = 52,
+        driver_tstamp = 60,
+        audio_tstamp_accuracy = 68,
+    );
+    assert_layout!(XferI, size = 12, align = 4, buf = 4, frames = 8);
+}
@@ -0,0 +1,6 @@
+mod ioctl;
+mod layout;
+mod types;
+
+pub use ioctl::*;
+pub use types::*;
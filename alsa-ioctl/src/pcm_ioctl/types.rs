@@ -0,0 +1,736 @@
+use super::super::{
+    bitfield_unit::BitfieldUnit,
+    string::AsciiString,
+    timespec::{TimePad, Timespec},
+};
+use bitflags::bitflags;
+use std::os::raw::{c_int, c_long, c_uchar, c_uint, c_ulong, c_ushort, c_void};
+
+/// Frame count, `snd_pcm_uframes_t`
+pub type Uframes = c_ulong;
+/// Signed frame count, `snd_pcm_sframes_t`
+pub type Sframes = c_long;
+
+/// pcm classes
+/// Used in `PcmInfo::dev_class`
+pub mod class {
+    use super::*;
+
+    /// standard mono or stereo device
+    pub const GENERIC: c_int = 0;
+    /// multichannel device
+    pub const MULTI: c_int = 1;
+    /// software modem class
+    pub const MODEM: c_int = 2;
+    /// digitizer class
+    pub const DIGITIZER: c_int = 3;
+}
+
+/// pcm subclasses
+/// Used in `PcmInfo::dev_subclass`
+pub mod subclass {
+    use super::*;
+
+    /// mono or stereo subdevices are mixed together
+    pub const GENERIC_MIX: c_int = 0;
+    /// multichannel subdevices are mixed together
+    pub const MULTI_MIX: c_int = 1;
+}
+
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PcmStream(pub c_int);
+
+impl PcmStream {
+    pub const PLAYBACK: Self = Self(0);
+    pub const CAPTURE: Self = Self(1);
+
+    pub fn is_playback(&self) -> bool {
+        self == &Self::PLAYBACK
+    }
+
+    pub fn is_capture(&self) -> bool {
+        self == &Self::CAPTURE
+    }
+}
+
+/// Value of the `HwParam::ACCESS` mask
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PcmAccess(pub c_int);
+
+impl PcmAccess {
+    /// interleaved mmap
+    pub const MMAP_INTERLEAVED: Self = Self(0);
+    /// noninterleaved mmap
+    pub const MMAP_NONINTERLEAVED: Self = Self(1);
+    /// complex mmap
+    pub const MMAP_COMPLEX: Self = Self(2);
+    /// readi/writei
+    pub const RW_INTERLEAVED: Self = Self(3);
+    /// readn/writen
+    pub const RW_NONINTERLEAVED: Self = Self(4);
+}
+
+/// Value of the `HwParam::FORMAT` mask
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PcmFormat(pub c_int);
+
+impl PcmFormat {
+    pub const S8: Self = Self(0);
+    pub const U8: Self = Self(1);
+    pub const S16_LE: Self = Self(2);
+    pub const S16_BE: Self = Self(3);
+    pub const U16_LE: Self = Self(4);
+    pub const U16_BE: Self = Self(5);
+    /// low three bytes
+    pub const S24_LE: Self = Self(6);
+    /// low three bytes
+    pub const S24_BE: Self = Self(7);
+    /// low three bytes
+    pub const U24_LE: Self = Self(8);
+    /// low three bytes
+    pub const U24_BE: Self = Self(9);
+    pub const S32_LE: Self = Self(10);
+    pub const S32_BE: Self = Self(11);
+    pub const U32_LE: Self = Self(12);
+    pub const U32_BE: Self = Self(13);
+    /// 4-byte float, IEEE-754 32-bit, range -1.0 to 1.0
+    pub const FLOAT_LE: Self = Self(14);
+    /// 4-byte float, IEEE-754 32-bit, range -1.0 to 1.0
+    pub const FLOAT_BE: Self = Self(15);
+    /// 8-byte float, IEEE-754 64-bit, range -1.0 to 1.0
+    pub const FLOAT64_LE: Self = Self(16);
+    /// 8-byte float, IEEE-754 64-bit, range -1.0 to 1.0
+    pub const FLOAT64_BE: Self = Self(17);
+    /// IEC-958 subframe, Little Endian
+    pub const IEC958_SUBFRAME_LE: Self = Self(18);
+    /// IEC-958 subframe, Big Endian
+    pub const IEC958_SUBFRAME_BE: Self = Self(19);
+    pub const MU_LAW: Self = Self(20);
+    pub const A_LAW: Self = Self(21);
+    pub const IMA_ADPCM: Self = Self(22);
+    pub const MPEG: Self = Self(23);
+    pub const GSM: Self = Self(24);
+    /// in four bytes, LSB justified
+    pub const S20_LE: Self = Self(25);
+    /// in four bytes, LSB justified
+    pub const S20_BE: Self = Self(26);
+    /// in four bytes, LSB justified
+    pub const U20_LE: Self = Self(27);
+    /// in four bytes, LSB justified
+    pub const U20_BE: Self = Self(28);
+    pub const SPECIAL: Self = Self(31);
+    /// in three bytes
+    pub const S24_3LE: Self = Self(32);
+    /// in three bytes
+    pub const S24_3BE: Self = Self(33);
+    /// in three bytes
+    pub const U24_3LE: Self = Self(34);
+    /// in three bytes
+    pub const U24_3BE: Self = Self(35);
+    /// in three bytes
+    pub const S20_3LE: Self = Self(36);
+    /// in three bytes
+    pub const S20_3BE: Self = Self(37);
+    /// in three bytes
+    pub const U20_3LE: Self = Self(38);
+    /// in three bytes
+    pub const U20_3BE: Self = Self(39);
+    /// in three bytes
+    pub const S18_3LE: Self = Self(40);
+    /// in three bytes
+    pub const S18_3BE: Self = Self(41);
+    /// in three bytes
+    pub const U18_3LE: Self = Self(42);
+    /// in three bytes
+    pub const U18_3BE: Self = Self(43);
+    /// 8 samples in 3 bytes
+    pub const G723_24: Self = Self(44);
+    /// 1 sample in 1 byte
+    pub const G723_24_1B: Self = Self(45);
+    /// 8 Samples in 5 bytes
+    pub const G723_40: Self = Self(46);
+    /// 1 sample in 1 byte
+    pub const G723_40_1B: Self = Self(47);
+    /// DSD, 1-byte samples DSD (x8)
+    pub const DSD_U8: Self = Self(48);
+    /// DSD, 2-byte samples DSD (x16), little endian
+    pub const DSD_U16_LE: Self = Self(49);
+    /// DSD, 4-byte samples DSD (x32), little endian
+    pub const DSD_U32_LE: Self = Self(50);
+    /// DSD, 2-byte samples DSD (x16), big endian
+    pub const DSD_U16_BE: Self = Self(51);
+    /// DSD, 4-byte samples DSD (x32), big endian
+    pub const DSD_U32_BE: Self = Self(52);
+
+    #[cfg(target_endian = "little")]
+    pub const S16: Self = Self::S16_LE;
+    #[cfg(target_endian = "little")]
+    pub const U16: Self = Self::U16_LE;
+    #[cfg(target_endian = "little")]
+    pub const S24: Self = Self::S24_LE;
+    #[cfg(target_endian = "little")]
+    pub const U24: Self = Self::U24_LE;
+    #[cfg(target_endian = "little")]
+    pub const S32: Self = Self::S32_LE;
+    #[cfg(target_endian = "little")]
+    pub const U32: Self = Self::U32_LE;
+    #[cfg(target_endian = "little")]
+    pub const FLOAT: Self = Self::FLOAT_LE;
+    #[cfg(target_endian = "little")]
+    pub const FLOAT64: Self = Self::FLOAT64_LE;
+    #[cfg(target_endian = "little")]
+    pub const IEC958_SUBFRAME: Self = Self::IEC958_SUBFRAME_LE;
+    #[cfg(target_endian = "little")]
+    pub const S20: Self = Self::S20_LE;
+    #[cfg(target_endian = "little")]
+    pub const U20: Self = Self::U20_LE;
+
+    #[cfg(target_endian = "big")]
+    pub const S16: Self = Self::S16_BE;
+    #[cfg(target_endian = "big")]
+    pub const U16: Self = Self::U16_BE;
+    #[cfg(target_endian = "big")]
+    pub const S24: Self = Self::S24_BE;
+    #[cfg(target_endian = "big")]
+    pub const U24: Self = Self::U24_BE;
+    #[cfg(target_endian = "big")]
+    pub const S32: Self = Self::S32_BE;
+    #[cfg(target_endian = "big")]
+    pub const U32: Self = Self::U32_BE;
+    #[cfg(target_endian = "big")]
+    pub const FLOAT: Self = Self::FLOAT_BE;
+    #[cfg(target_endian = "big")]
+    pub const FLOAT64: Self = Self::FLOAT64_BE;
+    #[cfg(target_endian = "big")]
+    pub const IEC958_SUBFRAME: Self = Self::IEC958_SUBFRAME_BE;
+    #[cfg(target_endian = "big")]
+    pub const S20: Self = Self::S20_BE;
+    #[cfg(target_endian = "big")]
+    pub const U20: Self = Self::U20_BE;
+}
+
+/// Value of the `HwParam::SUBFORMAT` mask
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PcmSubformat(pub c_int);
+
+impl PcmSubformat {
+    pub const STD: Self = Self(0);
+}
+
+bitflags! {
+    /// Used in `HwParams::info`
+    #[repr(transparent)]
+    pub struct PcmInfoFlags: c_uint {
+        /// hardware supports mmap
+        const MMAP = 0x00000001;
+        /// period data are valid during transfer
+        const MMAP_VALID = 0x00000002;
+        /// Double buffering needed for PCM start/stop
+        const DOUBLE = 0x00000004;
+        /// double buffering
+        const BATCH = 0x00000010;
+        /// need the explicit sync of appl_ptr update
+        const SYNC_APPLPTR = 0x00000020;
+        /// channels are interleaved
+        const INTERLEAVED = 0x00000100;
+        /// channels are not interleaved
+        const NONINTERLEAVED = 0x00000200;
+        /// complex frame organization (mmap only)
+        const COMPLEX = 0x00000400;
+        /// hardware transfer block of samples
+        const BLOCK_TRANSFER = 0x00010000;
+        /// hardware supports ADC (capture) overrange detection
+        const OVERRANGE = 0x00020000;
+        /// hardware supports stream resume after suspend
+        const RESUME = 0x00040000;
+        /// pause ioctl is supported
+        const PAUSE = 0x00080000;
+        /// only half duplex
+        const HALF_DUPLEX = 0x00100000;
+        /// playback and capture stream are somewhat correlated
+        const JOINT_DUPLEX = 0x00200000;
+        /// pcm support some kind of sync go
+        const SYNC_START = 0x00400000;
+        /// period wakeup can be disabled
+        const NO_PERIOD_WAKEUP = 0x00800000;
+        /// report hardware link audio time, reset on startup
+        const HAS_LINK_ATIME = 0x01000000;
+        /// report absolute hardware link audio time, not reset on startup
+        const HAS_LINK_ABSOLUTE_ATIME = 0x02000000;
+        /// report estimated link audio time
+        const HAS_LINK_ESTIMATED_ATIME = 0x04000000;
+        /// report synchronized audio/system time
+        const HAS_LINK_SYNCHRONIZED_ATIME = 0x08000000;
+        /// needs explicit sync of pointers and data
+        const EXPLICIT_SYNC = 0x10000000;
+        /// hardware can only support monotonic changes of appl_ptr
+        const NO_REWINDS = 0x20000000;
+        /// internal kernel flag - trigger in drain
+        const DRAIN_TRIGGER = 0x40000000;
+        /// internal kernel flag - FIFO size is in frames
+        const FIFO_IN_FRAMES = 0x80000000;
+    }
+}
+
+/// Used in `PcmStatus::state`
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PcmState(pub c_int);
+
+impl PcmState {
+    /// stream is open
+    pub const OPEN: Self = Self(0);
+    /// stream has a setup
+    pub const SETUP: Self = Self(1);
+    /// stream is ready to start
+    pub const PREPARED: Self = Self(2);
+    /// stream is running
+    pub const RUNNING: Self = Self(3);
+    /// stream reached an xrun
+    pub const XRUN: Self = Self(4);
+    /// stream is draining
+    pub const DRAINING: Self = Self(5);
+    /// stream is paused
+    pub const PAUSED: Self = Self(6);
+    /// hardware is suspended
+    pub const SUSPENDED: Self = Self(7);
+    /// hardware is disconnected
+    pub const DISCONNECTED: Self = Self(8);
+}
+
+/// hardware synchronization ID
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union PcmSyncId {
+    pub id: [c_uchar; 16usize],
+    pub id16: [c_ushort; 8usize],
+    pub id32: [c_uint; 4usize],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PcmInfo {
+    /// RO/WR (control): device number
+    pub device: c_uint,
+    /// RO/WR (control): subdevice number
+    pub subdevice: c_uint,
+    /// RO/WR (control): stream direction
+    pub stream: PcmStream, // c_int
+    /// R: card number
+    pub card: c_int,
+    /// ID (user selectable)
+    pub id: AsciiString<64>,
+    /// name of this device
+    pub name: AsciiString<80>,
+    /// subdevice name
+    pub subname: AsciiString<32>,
+    /// SNDRV_PCM_CLASS_*
+    pub dev_class: c_int,
+    /// SNDRV_PCM_SUBCLASS_*
+    pub dev_subclass: c_int,
+    pub subdevices_count: c_uint,
+    pub subdevices_avail: c_uint,
+    /// hardware synchronization ID
+    pub sync: PcmSyncId,
+    reserved: [c_uchar; 64usize],
+}
+
+/// Index of a parameter in `HwParams`
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HwParam(pub c_int);
+
+impl HwParam {
+    /// Access type
+    pub const ACCESS: Self = Self(0);
+    /// Format
+    pub const FORMAT: Self = Self(1);
+    /// Subformat
+    pub const SUBFORMAT: Self = Self(2);
+    pub const FIRST_MASK: Self = Self::ACCESS;
+    pub const LAST_MASK: Self = Self::SUBFORMAT;
+
+    /// Bits per sample
+    pub const SAMPLE_BITS: Self = Self(8);
+    /// Bits per frame
+    pub const FRAME_BITS: Self = Self(9);
+    /// Channels
+    pub const CHANNELS: Self = Self(10);
+    /// Approx rate
+    pub const RATE: Self = Self(11);
+    /// Approx distance between interrupts in us
+    pub const PERIOD_TIME: Self = Self(12);
+    /// Approx frames between interrupts
+    pub const PERIOD_SIZE: Self = Self(13);
+    /// Approx bytes between interrupts
+    pub const PERIOD_BYTES: Self = Self(14);
+    /// Approx interrupts per buffer
+    pub const PERIODS: Self = Self(15);
+    /// Approx duration of buffer in us
+    pub const BUFFER_TIME: Self = Self(16);
+    /// Size of buffer in frames
+    pub const BUFFER_SIZE: Self = Self(17);
+    /// Size of buffer in bytes
+    pub const BUFFER_BYTES: Self = Self(18);
+    /// Approx tick duration in us
+    pub const TICK_TIME: Self = Self(19);
+    pub const FIRST_INTERVAL: Self = Self::SAMPLE_BITS;
+    pub const LAST_INTERVAL: Self = Self::TICK_TIME;
+
+    pub const fn is_mask(&self) -> bool {
+        self.0 >= Self::FIRST_MASK.0 && self.0 <= Self::LAST_MASK.0
+    }
+
+    pub const fn is_interval(&self) -> bool {
+        self.0 >= Self::FIRST_INTERVAL.0 && self.0 <= Self::LAST_INTERVAL.0
+    }
+
+    /// Bit of this parameter in `HwParams::rmask` and `HwParams::cmask`
+    pub const fn bit(&self) -> c_uint {
+        1 << self.0
+    }
+}
+
+bitflags! {
+    /// Used in `HwParams::flags`
+    #[repr(transparent)]
+    pub struct HwParamsFlags: c_uint {
+        /// avoid rate resampling
+        const NORESAMPLE = 1<<0;
+        /// export buffer
+        const EXPORT_BUFFER = 1<<1;
+        /// disable period wakeups
+        const NO_PERIOD_WAKEUP = 1<<2;
+    }
+}
+
+/// Range of values of an interval parameter
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Interval {
+    pub min: c_uint,
+    pub max: c_uint,
+    _bitfield_align_1: [u8; 0],
+    _bitfield_1: BitfieldUnit<[u8; 1usize]>,
+}
+
+impl Interval {
+    /// Unconstrained interval
+    pub const ANY: Self = Self::new(0, c_uint::MAX);
+
+    pub const fn new(min: c_uint, max: c_uint) -> Self {
+        Self {
+            min,
+            max,
+            _bitfield_align_1: [],
+            _bitfield_1: BitfieldUnit::new([0]),
+        }
+    }
+
+    /// Interval containing only `val`
+    pub fn single(val: c_uint) -> Self {
+        let mut interval = Self::new(val, val);
+        interval.set_integer(true);
+        interval
+    }
+
+    /// Value of an interval narrowed down to a single integer
+    pub fn value(&self) -> Option<c_uint> {
+        if self.empty() || self.min != self.max || self.openmin() || self.openmax() {
+            None
+        } else {
+            Some(self.min)
+        }
+    }
+
+    #[inline]
+    pub fn openmin(&self) -> bool {
+        self._bitfield_1.get(0usize, 1u8) != 0
+    }
+
+    #[inline]
+    pub fn set_openmin(&mut self, val: bool) {
+        self._bitfield_1.set(0usize, 1u8, val as u64)
+    }
+
+    #[inline]
+    pub fn openmax(&self) -> bool {
+        self._bitfield_1.get(1usize, 1u8) != 0
+    }
+
+    #[inline]
+    pub fn set_openmax(&mut self, val: bool) {
+        self._bitfield_1.set(1usize, 1u8, val as u64)
+    }
+
+    #[inline]
+    pub fn integer(&self) -> bool {
+        self._bitfield_1.get(2usize, 1u8) != 0
+    }
+
+    #[inline]
+    pub fn set_integer(&mut self, val: bool) {
+        self._bitfield_1.set(2usize, 1u8, val as u64)
+    }
+
+    #[inline]
+    pub fn empty(&self) -> bool {
+        self._bitfield_1.get(3usize, 1u8) != 0
+    }
+
+    #[inline]
+    pub fn set_empty(&mut self, val: bool) {
+        self._bitfield_1.set(3usize, 1u8, val as u64)
+    }
+}
+
+pub const MASK_MAX: usize = 256;
+
+/// Set of allowed values of a mask parameter
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Mask {
+    pub bits: [u32; MASK_MAX.div_ceil(32)],
+}
+
+impl Mask {
+    /// Every value allowed
+    pub const ANY: Self = Self {
+        bits: [!0; MASK_MAX.div_ceil(32)],
+    };
+    /// No value allowed
+    pub const NONE: Self = Self {
+        bits: [0; MASK_MAX.div_ceil(32)],
+    };
+
+    /// Mask containing only `val`, empty if it is `MASK_MAX` or more
+    pub fn single(val: c_uint) -> Self {
+        let mut mask = Self::NONE;
+        mask.set(val);
+        mask
+    }
+
+    /// Allow `val`, ignored if it is `MASK_MAX` or more
+    pub fn set(&mut self, val: c_uint) {
+        let val = val as usize;
+        if val < MASK_MAX {
+            self.bits[val / 32] |= 1 << (val % 32);
+        }
+    }
+
+    /// Disallow `val`, ignored if it is `MASK_MAX` or more
+    pub fn reset(&mut self, val: c_uint) {
+        let val = val as usize;
+        if val < MASK_MAX {
+            self.bits[val / 32] &= !(1 << (val % 32));
+        }
+    }
+
+    pub fn test(&self, val: c_uint) -> bool {
+        let val = val as usize;
+        val < MASK_MAX && self.bits[val / 32] & (1 << (val % 32)) != 0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|b| *b == 0)
+    }
+
+    /// Allowed values in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = c_uint> + '_ {
+        (0..MASK_MAX as c_uint).filter(|v| self.test(*v))
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct HwParams {
+    pub flags: HwParamsFlags, // c_uint
+    pub masks: [Mask; (HwParam::LAST_MASK.0 - HwParam::FIRST_MASK.0 + 1) as usize],
+    /// reserved masks
+    mres: [Mask; 5usize],
+    pub intervals: [Interval; (HwParam::LAST_INTERVAL.0 - HwParam::FIRST_INTERVAL.0 + 1) as usize],
+    /// reserved intervals
+    ires: [Interval; 9usize],
+    /// W: requested masks
+    pub rmask: c_uint,
+    /// R: changed masks
+    pub cmask: c_uint,
+    /// R: Info flags for returned setup
+    pub info: PcmInfoFlags, // c_uint
+    /// R: used most significant bits
+    pub msbits: c_uint,
+    /// R: rate numerator
+    pub rate_num: c_uint,
+    /// R: rate denominator
+    pub rate_den: c_uint,
+    /// R: chip FIFO size in frames
+    pub fifo_size: Uframes,
+    reserved: [c_uchar; 64usize],
+}
+
+impl HwParams {
+    /// Unconstrained configuration space, the starting point for `hw_refine`
+    pub fn any() -> Self {
+        Self {
+            flags: HwParamsFlags::empty(),
+            masks: [Mask::ANY; 3],
+            mres: [Mask::NONE; 5],
+            intervals: [Interval::ANY; 12],
+            ires: [Interval::new(0, 0); 9usize],
+            rmask: !0,
+            cmask: 0,
+            info: PcmInfoFlags { bits: !0 },
+            msbits: 0,
+            rate_num: 0,
+            rate_den: 0,
+            fifo_size: 0,
+            reserved: [0; 64],
+        }
+    }
+
+    /// # Panics
+    /// If `param` is not a mask parameter
+    pub fn mask(&self, param: HwParam) -> &Mask {
+        assert!(param.is_mask());
+        &self.masks[(param.0 - HwParam::FIRST_MASK.0) as usize]
+    }
+
+    /// # Panics
+    /// If `param` is not an interval parameter
+    pub fn interval(&self, param: HwParam) -> &Interval {
+        assert!(param.is_interval());
+        &self.intervals[(param.0 - HwParam::FIRST_INTERVAL.0) as usize]
+    }
+
+    /// Replace a mask parameter and mark it as requested in `rmask`
+    ///
+    /// # Panics
+    /// If `param` is not a mask parameter
+    pub fn set_mask(&mut self, param: HwParam, mask: Mask) {
+        assert!(param.is_mask());
+        self.masks[(param.0 - HwParam::FIRST_MASK.0) as usize] = mask;
+        self.rmask |= param.bit();
+    }
+
+    /// Replace an interval parameter and mark it as requested in `rmask`
+    ///
+    /// # Panics
+    /// If `param` is not an interval parameter
+    pub fn set_interval(&mut self, param: HwParam, interval: Interval) {
+        assert!(param.is_interval());
+        self.intervals[(param.0 - HwParam::FIRST_INTERVAL.0) as usize] = interval;
+        self.rmask |= param.bit();
+    }
+
+    pub fn set_access(&mut self, access: PcmAccess) {
+        self.set_mask(HwParam::ACCESS, Mask::single(access.0 as c_uint));
+    }
+
+    pub fn set_format(&mut self, format: PcmFormat) {
+        self.set_mask(HwParam::FORMAT, Mask::single(format.0 as c_uint));
+    }
+
+    pub fn set_subformat(&mut self, subformat: PcmSubformat) {
+        self.set_mask(HwParam::SUBFORMAT, Mask::single(subformat.0 as c_uint));
+    }
+}
+
+/// Used in `SwParams::tstamp_mode`
+pub mod tstamp_mode {
+    use super::*;
+
+    pub const NONE: c_int = 0;
+    pub const ENABLE: c_int = 1;
+}
+
+/// Used in `SwParams::tstamp_type`
+pub mod tstamp_type {
+    use super::*;
+
+    /// gettimeofday equivalent
+    pub const GETTIMEOFDAY: c_uint = 0;
+    /// posix_clock_monotonic equivalent
+    pub const MONOTONIC: c_uint = 1;
+    /// monotonic_raw (no NTP)
+    pub const MONOTONIC_RAW: c_uint = 2;
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct SwParams {
+    /// timestamp mode
+    pub tstamp_mode: c_int,
+    pub period_step: c_uint,
+    /// min ticks to sleep
+    pub sleep_min: c_uint,
+    /// min avail frames for wakeup
+    pub avail_min: Uframes,
+    /// obsolete: xfer size need to be a multiple
+    pub xfer_align: Uframes,
+    /// min hw_avail frames for automatic start
+    pub start_threshold: Uframes,
+    /// min avail frames for automatic stop
+    pub stop_threshold: Uframes,
+    /// min distance from noise for silence filling
+    pub silence_threshold: Uframes,
+    /// silence block size
+    pub silence_size: Uframes,
+    /// pointers wrap point
+    pub boundary: Uframes,
+    /// protocol version
+    pub proto: c_uint,
+    /// timestamp type (req. proto >= 2.0.12)
+    pub tstamp_type: c_uint,
+    reserved: [c_uchar; 56usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct PcmStatus {
+    /// stream state
+    pub state: PcmState, // c_int
+    pad1: TimePad,
+    /// time when stream was started/stopped/paused
+    pub trigger_tstamp: Timespec,
+    /// reference timestamp
+    pub tstamp: Timespec,
+    /// appl ptr
+    pub appl_ptr: Uframes,
+    /// hw ptr
+    pub hw_ptr: Uframes,
+    /// current delay in frames
+    pub delay: Sframes,
+    /// number of frames available
+    pub avail: Uframes,
+    /// max frames available on hw since last status
+    pub avail_max: Uframes,
+    /// count of ADC (capture) overrange detections from last status
+    pub overrange: Uframes,
+    /// suspended stream state
+    pub suspended_state: PcmState, // c_int
+    /// used for configs/report to/from userspace
+    pub audio_tstamp_data: u32,
+    /// sample counter, wall clock, PHC or on-demand sync'ed
+    pub audio_tstamp: Timespec,
+    /// useful in case reference system tstamp is reported with delay
+    pub driver_tstamp: Timespec,
+    /// in ns units, only valid if indicated in audio_tstamp_data
+    pub audio_tstamp_accuracy: u32,
+    reserved: [c_uchar; 52 - 2 * std::mem::size_of::<Timespec>()],
+}
+
+/// Interleaved transfer for `writei_frames` and `readi_frames`
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct XferI {
+    /// R: transferred frames or negative error code
+    pub result: Sframes,
+    /// W: interleaved frames
+    pub buf: *mut c_void,
+    /// W: frames in `buf`
+    pub frames: Uframes,
+}
@@ -0,0 +1,38 @@
+use crate::Version;
+
+use super::types;
+
+// #define SNDRV_RAWMIDI_IOCTL_PVERSION	_IOR('W', 0x00, int)
+ioctl_read! {
+    pversion, b'W', 0x00, Version
+}
+
+// #define SNDRV_RAWMIDI_IOCTL_INFO	_IOR('W', 0x01, struct snd_rawmidi_info)
+ioctl_read! {
+    info, b'W', 0x01, types::RawmidiInfo
+}
+
+// #define SNDRV_RAWMIDI_IOCTL_USER_PVERSION _IOW('W', 0x02, int)
+ioctl_write_ptr! {
+    user_pversion, b'W', 0x02, Version
+}
+
+// #define SNDRV_RAWMIDI_IOCTL_PARAMS	_IOWR('W', 0x10, struct snd_rawmidi_params)
+ioctl_readwrite! {
+    params, b'W', 0x10, types::RawmidiParams
+}
+
+// #define SNDRV_RAWMIDI_IOCTL_STATUS	_IOWR('W', 0x20, struct snd_rawmidi_status)
+ioctl_readwrite! {
+    status, b'W', 0x20, types::RawmidiStatus
+}
+
+// #define SNDRV_RAWMIDI_IOCTL_DROP	_IOW('W', 0x30, int)
+ioctl_write_ptr! {
+    drop, b'W', 0x30, types::RawmidiStream
+}
+
+// #define SNDRV_RAWMIDI_IOCTL_DRAIN	_IOW('W', 0x31, int)
+ioctl_write_ptr! {
+    drain, b'W', 0x31, types::RawmidiStream
+}
@@ -0,0 +1,5 @@
+mod ioctl;
+mod types;
+
+pub use ioctl::*;
+pub use types::*;
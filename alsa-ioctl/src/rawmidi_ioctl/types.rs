@@ -0,0 +1,169 @@
+use super::super::{
+    bitfield_unit::BitfieldUnit,
+    string::AsciiString,
+    timespec::{TimePad, Timespec},
+};
+use bitflags::bitflags;
+use std::os::raw::{c_int, c_uchar, c_uint};
+
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RawmidiStream(pub c_int);
+
+impl RawmidiStream {
+    pub const OUTPUT: Self = Self(0);
+    pub const INPUT: Self = Self(1);
+
+    pub fn is_output(&self) -> bool {
+        self == &Self::OUTPUT
+    }
+
+    pub fn is_input(&self) -> bool {
+        self == &Self::INPUT
+    }
+}
+
+bitflags! {
+    /// Used in `RawmidiInfo::flags`
+    #[repr(transparent)]
+    pub struct RawmidiInfoFlags: c_uint {
+        const OUTPUT = 0x00000001;
+        const INPUT = 0x00000002;
+        const DUPLEX = 0x00000004;
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct RawmidiInfo {
+    /// RO/WR (control): device number
+    pub device: c_uint,
+    /// RO/WR (control): subdevice number
+    pub subdevice: c_uint,
+    /// WR: stream
+    pub stream: RawmidiStream, // c_int
+    /// R: card number
+    pub card: c_int,
+    /// SNDRV_RAWMIDI_INFO_XXXX
+    pub flags: RawmidiInfoFlags, // c_uint
+    /// ID (user selectable)
+    pub id: AsciiString<64>,
+    /// name of device
+    pub name: AsciiString<80>,
+    /// name of active or selected subdevice
+    pub subname: AsciiString<32>,
+    pub subdevices_count: c_uint,
+    pub subdevices_avail: c_uint,
+    reserved: [c_uchar; 64usize],
+}
+
+/// Input framing and timestamping mode
+/// Used in `RawmidiParams::mode`
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RawmidiMode(pub c_uint);
+
+impl RawmidiMode {
+    pub const FRAMING_MASK: c_uint = 7;
+    pub const FRAMING_SHIFT: c_uint = 0;
+    /// raw byte stream
+    pub const FRAMING_NONE: c_uint = 0;
+    /// input is framed into `FramingTstamp` records
+    pub const FRAMING_TSTAMP: c_uint = 1 << 0;
+
+    pub const CLOCK_MASK: c_uint = 7 << 3;
+    pub const CLOCK_SHIFT: c_uint = 3;
+    pub const CLOCK_NONE: c_uint = 0 << 3;
+    pub const CLOCK_REALTIME: c_uint = 1 << 3;
+    pub const CLOCK_MONOTONIC: c_uint = 2 << 3;
+    pub const CLOCK_MONOTONIC_RAW: c_uint = 3 << 3;
+
+    pub const fn new(framing: c_uint, clock: c_uint) -> Self {
+        Self((framing & Self::FRAMING_MASK) | (clock & Self::CLOCK_MASK))
+    }
+
+    // framing type
+    pub const fn framing(&self) -> c_uint {
+        self.0 & Self::FRAMING_MASK
+    }
+
+    /// input is framed into `FramingTstamp` records
+    pub const fn is_framing_tstamp(&self) -> bool {
+        self.framing() == Self::FRAMING_TSTAMP
+    }
+
+    // clock type
+    pub const fn clock(&self) -> c_uint {
+        self.0 & Self::CLOCK_MASK
+    }
+}
+
+pub const FRAMING_DATA_LENGTH: usize = 16;
+
+/// Frame read from an input stream in `RawmidiMode::FRAMING_TSTAMP` mode
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct FramingTstamp {
+    /// For now, frame_type is always 0. Midi 2.0 is expected to add new
+    /// types here. Applications are expected to skip unknown frame types.
+    pub frame_type: u8,
+    /// number of valid bytes in data field
+    pub length: u8,
+    reserved: [u8; 2usize],
+    /// nanoseconds
+    pub tv_nsec: u32,
+    /// seconds
+    pub tv_sec: u64,
+    pub data: [u8; FRAMING_DATA_LENGTH],
+}
+
+impl FramingTstamp {
+    /// Valid bytes of `data`
+    pub fn bytes(&self) -> &[u8] {
+        let len = (self.length as usize).min(FRAMING_DATA_LENGTH);
+        &self.data[..len]
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct RawmidiParams {
+    pub stream: RawmidiStream, // c_int
+    /// queue size in bytes
+    pub buffer_size: usize,
+    /// minimum avail bytes for wakeup
+    pub avail_min: usize,
+    _bitfield_align_1: [u8; 0],
+    _bitfield_1: BitfieldUnit<[u8; 1usize]>,
+    /// For input data only, frame incoming data
+    pub mode: RawmidiMode, // c_uint
+    reserved: [c_uchar; 12usize],
+}
+
+impl RawmidiParams {
+    /// do not send active sensing byte in close()
+    #[inline]
+    pub fn no_active_sensing(&self) -> bool {
+        self._bitfield_1.get(0usize, 1u8) != 0
+    }
+
+    /// do not send active sensing byte in close()
+    #[inline]
+    pub fn set_no_active_sensing(&mut self, val: bool) {
+        self._bitfield_1.set(0usize, 1u8, val as u64)
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct RawmidiStatus {
+    pub stream: RawmidiStream, // c_int
+    pad1: TimePad,
+    /// Timestamp
+    pub tstamp: Timespec,
+    /// available bytes
+    pub avail: usize,
+    /// count of overruns since last status (in bytes)
+    pub xruns: usize,
+    reserved: [c_uchar; 16usize],
+}
@@ -0,0 +1,389 @@
+//! `Default`, `new` and `with_*` setters for the ioctl arguments
+//!
+//! `Default` is all zeroes like a `memset` in C, so `reserved` fields and
+//! `PortInfo::kernel` are left as the kernel expects them.
+
+use std::os::raw::{c_char, c_int, c_uchar, c_uint};
+
+use super::super::string::AsciiString;
+use super::types::*;
+
+macro_rules! zeroed_default {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Default for $ty {
+                fn default() -> Self {
+                    // plain old data, zero is a valid value of every field
+                    unsafe { std::mem::zeroed() }
+                }
+            }
+        )*
+    };
+}
+
+/// Builder style setters of public fields
+macro_rules! with_setters {
+    ($ty:ty { $($setter:ident => $field:ident: $fty:ty),* $(,)? }) => {
+        impl $ty {
+            $(
+                pub fn $setter(mut self, $field: $fty) -> Self {
+                    self.$field = $field;
+                    self
+                }
+            )*
+        }
+    };
+}
+
+zeroed_default!(
+    Addr,
+    Connect,
+    EvNote,
+    EvCtrl,
+    EvRaw8,
+    EvRaw32,
+    EvExt,
+    EvResult,
+    RealTime,
+    Timestamp,
+    QueueSkew,
+    EvQueueControl,
+    EvQueueControlParam,
+    EvQuote,
+    Event,
+    EventData,
+    UmpEvent,
+    UmpEventData,
+    EventBounce,
+    SystemInfo,
+    RunningInfo,
+    ClientInfo,
+    ClientPool,
+    RemoveEvents,
+    PortInfo,
+    QueueInfo,
+    QueueStatus,
+    QueueTempo,
+    QueueTimer,
+    QueueTimerUnion,
+    QueueTimerAlsa,
+    QueueClient,
+    PortSubscribe,
+    QuerySubscribe,
+    UmpEndpointInfo,
+    UmpBlockInfo,
+    ClientUmpInfo,
+);
+
+impl Addr {
+    pub const fn new(client: c_uchar, port: c_uchar) -> Self {
+        Self { client, port }
+    }
+}
+
+impl Connect {
+    pub const fn new(sender: Addr, dest: Addr) -> Self {
+        Self { sender, dest }
+    }
+}
+
+impl EvNote {
+    pub fn new(channel: c_uchar, note: c_uchar, velocity: c_uchar) -> Self {
+        Self {
+            channel,
+            note,
+            velocity,
+            off_velocity: 0,
+            duration: 0,
+        }
+    }
+}
+
+with_setters!(EvNote {
+    with_off_velocity => off_velocity: c_uchar,
+    with_duration => duration: c_uint,
+});
+
+impl EvCtrl {
+    pub fn new(channel: c_uchar, param: c_uint, value: c_int) -> Self {
+        let mut this = Self::default();
+        this.channel = channel;
+        this.param = param;
+        this.value = value;
+        this
+    }
+}
+
+impl RealTime {
+    pub const fn new(tv_sec: c_uint, tv_nsec: c_uint) -> Self {
+        Self { tv_sec, tv_nsec }
+    }
+}
+
+impl Timestamp {
+    pub const fn tick(tick: TickTimeT) -> Self {
+        // `tick` shares its bytes with `time.tv_sec`, this keeps the whole union initialized
+        Self {
+            time: RealTime::new(tick, 0),
+        }
+    }
+
+    pub const fn real(time: RealTime) -> Self {
+        Self { time }
+    }
+}
+
+macro_rules! event_data_from {
+    ($($field:ident: $ty:ty),* $(,)?) => {
+        $(
+            impl From<$ty> for EventData {
+                fn from($field: $ty) -> Self {
+                    let mut data = Self::default();
+                    data.$field = $field;
+                    data
+                }
+            }
+        )*
+    };
+}
+
+event_data_from!(
+    note: EvNote,
+    control: EvCtrl,
+    raw8: EvRaw8,
+    raw32: EvRaw32,
+    ext: EvExt,
+    queue: EvQueueControl,
+    addr: Addr,
+    connect: Connect,
+    result: EvResult,
+    quote: EvQuote,
+);
+
+impl EvQueueControl {
+    pub fn new(queue: QueueId, param: EvQueueControlParam) -> Self {
+        let mut this = Self::default();
+        this.queue = queue;
+        this.param = param;
+        this
+    }
+}
+
+impl Event {
+    /// Direct (not queued) event of `type_` sent to the subscribers of the source port
+    pub fn new(type_: EventType) -> Self {
+        Self {
+            type_,
+            queue: QueueId::DIRECT,
+            dest: Addr::SUBSCRIBERS,
+            ..Default::default()
+        }
+    }
+}
+
+with_setters!(Event {
+    with_flags => flags: EventFlags,
+    with_tag => tag: c_char,
+    with_queue => queue: QueueId,
+    with_time => time: Timestamp,
+    with_source => source: Addr,
+    with_dest => dest: Addr,
+    with_data => data: EventData,
+});
+
+impl RunningInfo {
+    /// Byte order and word size of this target for `client`
+    pub fn native(client: c_uchar) -> Self {
+        let mut this = Self::default();
+        this.client = client;
+        this.big_endian = cfg!(target_endian = "big") as c_uchar;
+        this.cpu_mode = std::mem::size_of::<usize>() as c_uchar;
+        this
+    }
+}
+
+impl ClientInfo {
+    pub fn new(client: c_int) -> Self {
+        let mut this = Self::default();
+        this.client = client;
+        this
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = AsciiString::new(name);
+        self
+    }
+}
+
+with_setters!(ClientInfo {
+    with_type => type_: ClientType,
+    with_filter => filter: Filter,
+    with_multicast_filter => multicast_filter: [c_uchar; 8usize],
+    with_event_filter => event_filter: [c_uchar; 32usize],
+    with_midi_version => midi_version: MidiVersion,
+    with_group_filter => group_filter: c_uint,
+});
+
+impl ClientPool {
+    pub fn new(client: c_int) -> Self {
+        let mut this = Self::default();
+        this.client = client;
+        this
+    }
+}
+
+with_setters!(ClientPool {
+    with_output_pool => output_pool: c_int,
+    with_input_pool => input_pool: c_int,
+    with_output_room => output_room: c_int,
+});
+
+impl RemoveEvents {
+    pub fn new(remove_mode: RemoveMode) -> Self {
+        let mut this = Self::default();
+        this.remove_mode = remove_mode;
+        this
+    }
+}
+
+with_setters!(RemoveEvents {
+    with_time => time: Timestamp,
+    with_queue => queue: QueueId,
+    with_dest => dest: Addr,
+    with_channel => channel: c_uchar,
+    with_type => type_: c_int,
+    with_tag => tag: c_char,
+});
+
+impl PortInfo {
+    pub fn new(addr: Addr) -> Self {
+        let mut this = Self::default();
+        this.addr = addr;
+        this
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = AsciiString::new(name);
+        self
+    }
+}
+
+with_setters!(PortInfo {
+    with_capability => capability: PortCapability,
+    with_type => type_: PortType,
+    with_midi_channels => midi_channels: c_int,
+    with_midi_voices => midi_voices: c_int,
+    with_synth_voices => synth_voices: c_int,
+    with_flags => flags: PortFlags,
+    with_time_queue => time_queue: QueueId,
+    with_direction => direction: PortDirection,
+    with_ump_group => ump_group: c_uchar,
+});
+
+impl QueueInfo {
+    pub fn new(queue: QueueId) -> Self {
+        let mut this = Self::default();
+        this.queue = queue;
+        this
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = AsciiString::new(name);
+        self
+    }
+
+    pub fn with_locked(mut self, locked: bool) -> Self {
+        self.set_locked(locked as c_uint);
+        self
+    }
+}
+
+with_setters!(QueueInfo {
+    with_owner => owner: c_int,
+    with_flags => flags: QueueFlag,
+});
+
+impl QueueStatus {
+    pub fn new(queue: QueueId) -> Self {
+        let mut this = Self::default();
+        this.queue = queue;
+        this
+    }
+}
+
+impl QueueTempo {
+    pub fn new(queue: QueueId) -> Self {
+        let mut this = Self::default();
+        this.queue = queue;
+        this
+    }
+}
+
+with_setters!(QueueTempo {
+    with_tempo => tempo: c_uint,
+    with_ppq => ppq: c_int,
+    with_skew_value => skew_value: c_uint,
+    with_skew_base => skew_base: c_uint,
+});
+
+impl QueueTimer {
+    pub fn new(queue: QueueId) -> Self {
+        let mut this = Self::default();
+        this.queue = queue;
+        this
+    }
+
+    /// Drive the queue from the ALSA timer `id`
+    pub fn with_alsa_timer(mut self, id: SndTimerId, resolution: c_uint) -> Self {
+        self.type_ = QueueTimerType::ALSA;
+        self.timer = QueueTimerUnion {
+            alsa: QueueTimerAlsa { id, resolution },
+        };
+        self
+    }
+}
+
+with_setters!(QueueTimer {
+    with_type => type_: QueueTimerType,
+});
+
+impl QueueClient {
+    pub fn new(queue: QueueId, client: c_int) -> Self {
+        let mut this = Self::default();
+        this.queue = queue;
+        this.client = client;
+        this
+    }
+}
+
+with_setters!(QueueClient {
+    with_used => used: c_int,
+});
+
+impl PortSubscribe {
+    pub fn new(sender: Addr, dest: Addr) -> Self {
+        let mut this = Self::default();
+        this.sender = sender;
+        this.dest = dest;
+        this
+    }
+}
+
+with_setters!(PortSubscribe {
+    with_voices => voices: c_uint,
+    with_flags => flags: SubscribeFlags,
+    with_queue => queue: QueueId,
+});
+
+impl QuerySubscribe {
+    pub fn new(root: Addr, type_: QuerySubscribeType) -> Self {
+        let mut this = Self::default();
+        this.root = root;
+        this.type_ = type_;
+        this
+    }
+}
+
+with_setters!(QuerySubscribe {
+    with_index => index: c_int,
+});
@@ -1,41 +1,7 @@
-use std::os::fd::AsFd;
-
-use rustix::{
-    io::Result,
-    ioctl::{ioctl, Getter, ReadOpcode, ReadWriteOpcode, Setter, Updater, WriteOpcode},
-};
-
 use crate::Version;
 
 use super::types;
 
-macro_rules! ioctl_readwrite {
-    ($name:ident, $ioty:expr, $nr:expr, $ty:ty) => {
-        pub fn $name(fd: impl AsFd, data: &mut $ty) -> Result<()> {
-            type Opcode = ReadWriteOpcode<$ioty, $nr, $ty>;
-            unsafe { ioctl(fd, Updater::<Opcode, $ty>::new(data)) }
-        }
-    };
-}
-
-macro_rules! ioctl_read {
-    ($name:ident, $ioty:expr, $nr:expr, $ty:ty) => {
-        pub fn $name(fd: impl AsFd) -> Result<$ty> {
-            type Opcode = ReadOpcode<$ioty, $nr, $ty>;
-            unsafe { ioctl(fd, Getter::<Opcode, $ty>::new()) }
-        }
-    };
-}
-
-macro_rules! ioctl_write_ptr {
-    ($name:ident, $ioty:expr, $nr:expr, $ty:ty) => {
-        pub fn $name(fd: impl AsFd, data: $ty) -> Result<()> {
-            type Opcode = WriteOpcode<$ioty, $nr, $ty>;
-            unsafe { ioctl(fd, Setter::<Opcode, $ty>::new(data)) }
-        }
-    };
-}
-
 // #define SNDRV_SEQ_IOCTL_PVERSION	_IOR ('S', 0x00, int)
 ioctl_read! {
     pversion, b'S', 0x00, Version
@@ -56,6 +22,11 @@ ioctl_readwrite! {
     running_mode, b'S', 0x03, types::RunningInfo
 }
 
+// #define SNDRV_SEQ_IOCTL_USER_PVERSION	_IOW('S', 0x04, int)
+ioctl_write_ptr! {
+    user_pversion, b'S', 0x04, Version
+}
+
 //
 // Client
 //
@@ -70,6 +41,16 @@ ioctl_write_ptr! {
     set_client_info, b'S', 0x11, types::ClientInfo
 }
 
+// #define SNDRV_SEQ_IOCTL_GET_CLIENT_UMP_INFO	_IOWR('S', 0x12, struct snd_seq_client_ump_info)
+ioctl_readwrite! {
+    get_client_ump_info, b'S', 0x12, types::ClientUmpInfo
+}
+
+// #define SNDRV_SEQ_IOCTL_SET_CLIENT_UMP_INFO	_IOWR('S', 0x13, struct snd_seq_client_ump_info)
+ioctl_readwrite! {
+    set_client_ump_info, b'S', 0x13, types::ClientUmpInfo
+}
+
 //
 // Port
 //
@@ -0,0 +1,748 @@
+// Generated by data/seq_layout.sh from data/asequencer.h, do not edit.
+// SNDRV_SEQ_VERSION 1.0.3, asequencer_commit_sha 2dde18cd1d8fac735875f2e4987f11817cc0bc2c
+
+use super::types::*;
+
+#[cfg(target_pointer_width = "64")]
+mod lp64 {
+    use super::*;
+
+    assert_layout!(
+        SndTimerId,
+        size = 20,
+        align = 4,
+        dev_class = 0,
+        dev_sclass = 4,
+        card = 8,
+        device = 12,
+        subdevice = 16,
+    );
+    assert_layout!(
+        UmpEndpointInfo,
+        size = 328,
+        align = 1,
+        card = 0,
+        device = 4,
+        flags = 8,
+        protocol_caps = 12,
+        protocol = 16,
+        num_blocks = 20,
+        version = 24,
+        family_id = 26,
+        model_id = 28,
+        manufacturer_id = 30,
+        sw_revision = 34,
+        name = 40,
+        product_id = 168,
+    );
+    assert_layout!(
+        UmpBlockInfo,
+        size = 180,
+        align = 1,
+        card = 0,
+        device = 4,
+        block_id = 8,
+        direction = 9,
+        active = 10,
+        first_group = 11,
+        num_groups = 12,
+        midi_ci_version = 13,
+        sysex8_streams = 14,
+        ui_hint = 15,
+        flags = 16,
+        name = 20,
+    );
+    assert_layout!(
+        Addr,
+        size = 2,
+        align = 1,
+        client = 0,
+        port = 1,
+    );
+    assert_layout!(
+        Connect,
+        size = 4,
+        align = 1,
+        sender = 0,
+        dest = 2,
+    );
+    assert_layout!(
+        EvNote,
+        size = 8,
+        align = 4,
+        channel = 0,
+        note = 1,
+        velocity = 2,
+        off_velocity = 3,
+        duration = 4,
+    );
+    assert_layout!(
+        EvCtrl,
+        size = 12,
+        align = 4,
+        channel = 0,
+        param = 4,
+        value = 8,
+    );
+    assert_layout!(
+        EvRaw8,
+        size = 12,
+        align = 1,
+        d = 0,
+    );
+    assert_layout!(
+        EvRaw32,
+        size = 12,
+        align = 4,
+        d = 0,
+    );
+    assert_layout!(
+        EvExt,
+        size = 12,
+        align = 1,
+        len = 0,
+        ptr = 4,
+    );
+    assert_layout!(
+        EvResult,
+        size = 8,
+        align = 4,
+        event = 0,
+        result = 4,
+    );
+    assert_layout!(
+        RealTime,
+        size = 8,
+        align = 4,
+        tv_sec = 0,
+        tv_nsec = 4,
+    );
+    assert_layout!(
+        Timestamp,
+        size = 8,
+        align = 4,
+        tick = 0,
+        time = 0,
+    );
+    assert_layout!(
+        QueueSkew,
+        size = 8,
+        align = 4,
+        value = 0,
+        base = 4,
+    );
+    assert_layout!(
+        EvQueueControl,
+        size = 12,
+        align = 4,
+        queue = 0,
+        param = 4,
+    );
+    assert_layout!(
+        EvQueueControlParam,
+        size = 8,
+        align = 4,
+        value = 0,
+        time = 0,
+        position = 0,
+        skew = 0,
+        d32 = 0,
+        d8 = 0,
+    );
+    assert_layout!(
+        EvQuote,
+        size = 12,
+        align = 1,
+        origin = 0,
+        value = 2,
+        event = 4,
+    );
+    assert_layout!(
+        EventData,
+        size = 12,
+        align = 4,
+        note = 0,
+        control = 0,
+        raw8 = 0,
+        raw32 = 0,
+        ext = 0,
+        queue = 0,
+        time = 0,
+        addr = 0,
+        connect = 0,
+        result = 0,
+        quote = 0,
+    );
+    assert_layout!(
+        Event,
+        size = 28,
+        align = 4,
+        type_ = 0,
+        flags = 1,
+        tag = 2,
+        queue = 3,
+        time = 4,
+        source = 12,
+        dest = 14,
+        data = 16,
+    );
+    assert_layout!(
+        UmpEvent,
+        size = 32,
+        align = 4,
+        type_ = 0,
+        flags = 1,
+        tag = 2,
+        queue = 3,
+        time = 4,
+        source = 12,
+        dest = 14,
+        data = 16,
+    );
+    assert_layout!(
+        EventBounce,
+        size = 32,
+        align = 4,
+        err = 0,
+        event = 4,
+    );
+    assert_layout!(
+        SystemInfo,
+        size = 48,
+        align = 4,
+        queues = 0,
+        clients = 4,
+        ports = 8,
+        channels = 12,
+        cur_clients = 16,
+        cur_queues = 20,
+    );
+    assert_layout!(
+        RunningInfo,
+        size = 16,
+        align = 1,
+        client = 0,
+        big_endian = 1,
+        cpu_mode = 2,
+    );
+    assert_layout!(
+        ClientInfo,
+        size = 188,
+        align = 4,
+        client = 0,
+        type_ = 4,
+        name = 8,
+        filter = 72,
+        multicast_filter = 76,
+        event_filter = 84,
+        num_ports = 116,
+        event_lost = 120,
+        card = 124,
+        pid = 128,
+        midi_version = 132,
+        group_filter = 136,
+    );
+    assert_layout!(
+        ClientPool,
+        size = 88,
+        align = 4,
+        client = 0,
+        output_pool = 4,
+        input_pool = 8,
+        output_room = 12,
+        output_free = 16,
+        input_free = 20,
+    );
+    assert_layout!(
+        RemoveEvents,
+        size = 64,
+        align = 4,
+        remove_mode = 0,
+        time = 4,
+        queue = 12,
+        dest = 13,
+        channel = 15,
+        type_ = 16,
+        tag = 20,
+    );
+    assert_layout!(
+        PortInfo,
+        size = 168,
+        align = 8,
+        addr = 0,
+        name = 2,
+        capability = 68,
+        type_ = 72,
+        midi_channels = 76,
+        midi_voices = 80,
+        synth_voices = 84,
+        read_use = 88,
+        write_use = 92,
+        kernel = 96,
+        flags = 104,
+        time_queue = 108,
+        direction = 109,
+        ump_group = 110,
+    );
+    assert_layout!(
+        QueueInfo,
+        size = 140,
+        align = 4,
+        queue = 0,
+        owner = 4,
+        name = 9,
+        flags = 76,
+    );
+    assert_layout!(
+        QueueStatus,
+        size = 92,
+        align = 4,
+        queue = 0,
+        events = 4,
+        tick = 8,
+        time = 12,
+        running = 20,
+        flags = 24,
+    );
+    assert_layout!(
+        QueueTempo,
+        size = 44,
+        align = 4,
+        queue = 0,
+        tempo = 4,
+        ppq = 8,
+        skew_value = 12,
+        skew_base = 16,
+    );
+    assert_layout!(
+        QueueTimer,
+        size = 96,
+        align = 4,
+        queue = 0,
+        type_ = 4,
+        timer = 8,
+    );
+    assert_layout!(
+        QueueTimerUnion,
+        size = 24,
+        align = 4,
+        alsa = 0,
+    );
+    assert_layout!(
+        QueueTimerAlsa,
+        size = 24,
+        align = 4,
+        id = 0,
+        resolution = 20,
+    );
+    assert_layout!(
+        QueueClient,
+        size = 76,
+        align = 4,
+        queue = 0,
+        client = 4,
+        used = 8,
+    );
+    assert_layout!(
+        PortSubscribe,
+        size = 80,
+        align = 4,
+        sender = 0,
+        dest = 2,
+        voices = 4,
+        flags = 8,
+        queue = 12,
+    );
+    assert_layout!(
+        QuerySubscribe,
+        size = 88,
+        align = 4,
+        root = 0,
+        type_ = 4,
+        index = 8,
+        num_subs = 12,
+        addr = 16,
+        queue = 18,
+        flags = 20,
+    );
+    assert_layout!(
+        ClientUmpInfo,
+        size = 520,
+        align = 1,
+        client = 0,
+        type_ = 4,
+        info = 8,
+    );
+}
+
+#[cfg(target_pointer_width = "32")]
+mod ilp32 {
+    use super::*;
+
+    assert_layout!(
+        SndTimerId,
+        size = 20,
+        align = 4,
+        dev_class = 0,
+        dev_sclass = 4,
+        card = 8,
+        device = 12,
+        subdevice = 16,
+    );
+    assert_layout!(
+        UmpEndpointInfo,
+        size = 328,
+        align = 1,
+        card = 0,
+        device = 4,
+        flags = 8,
+        protocol_caps = 12,
+        protocol = 16,
+        num_blocks = 20,
+        version = 24,
+        family_id = 26,
+        model_id = 28,
+        manufacturer_id = 30,
+        sw_revision = 34,
+        name = 40,
+        product_id = 168,
+    );
+    assert_layout!(
+        UmpBlockInfo,
+        size = 180,
+        align = 1,
+        card = 0,
+        device = 4,
+        block_id = 8,
+        direction = 9,
+        active = 10,
+        first_group = 11,
+        num_groups = 12,
+        midi_ci_version = 13,
+        sysex8_streams = 14,
+        ui_hint = 15,
+        flags = 16,
+        name = 20,
+    );
+    assert_layout!(
+        Addr,
+        size = 2,
+        align = 1,
+        client = 0,
+        port = 1,
+    );
+    assert_layout!(
+        Connect,
+        size = 4,
+        align = 1,
+        sender = 0,
+        dest = 2,
+    );
+    assert_layout!(
+        EvNote,
+        size = 8,
+        align = 4,
+        channel = 0,
+        note = 1,
+        velocity = 2,
+        off_velocity = 3,
+        duration = 4,
+    );
+    assert_layout!(
+        EvCtrl,
+        size = 12,
+        align = 4,
+        channel = 0,
+        param = 4,
+        value = 8,
+    );
+    assert_layout!(
+        EvRaw8,
+        size = 12,
+        align = 1,
+        d = 0,
+    );
+    assert_layout!(
+        EvRaw32,
+        size = 12,
+        align = 4,
+        d = 0,
+    );
+    assert_layout!(
+        EvExt,
+        size = 8,
+        align = 1,
+        len = 0,
+        ptr = 4,
+    );
+    assert_layout!(
+        EvResult,
+        size = 8,
+        align = 4,
+        event = 0,
+        result = 4,
+    );
+    assert_layout!(
+        RealTime,
+        size = 8,
+        align = 4,
+        tv_sec = 0,
+        tv_nsec = 4,
+    );
+    assert_layout!(
+        Timestamp,
+        size = 8,
+        align = 4,
+        tick = 0,
+        time = 0,
+    );
+    assert_layout!(
+        QueueSkew,
+        size = 8,
+        align = 4,
+        value = 0,
+        base = 4,
+    );
+    assert_layout!(
+        EvQueueControl,
+        size = 12,
+        align = 4,
+        queue = 0,
+        param = 4,
+    );
+    assert_layout!(
+        EvQueueControlParam,
+        size = 8,
+        align = 4,
+        value = 0,
+        time = 0,
+        position = 0,
+        skew = 0,
+        d32 = 0,
+        d8 = 0,
+    );
+    assert_layout!(
+        EvQuote,
+        size = 8,
+        align = 1,
+        origin = 0,
+        value = 2,
+        event = 4,
+    );
+    assert_layout!(
+        EventData,
+        size = 12,
+        align = 4,
+        note = 0,
+        control = 0,
+        raw8 = 0,
+        raw32 = 0,
+        ext = 0,
+        queue = 0,
+        time = 0,
+        addr = 0,
+        connect = 0,
+        result = 0,
+        quote = 0,
+    );
+    assert_layout!(
+        Event,
+        size = 28,
+        align = 4,
+        type_ = 0,
+        flags = 1,
+        tag = 2,
+        queue = 3,
+        time = 4,
+        source = 12,
+        dest = 14,
+        data = 16,
+    );
+    assert_layout!(
+        UmpEvent,
+        size = 32,
+        align = 4,
+        type_ = 0,
+        flags = 1,
+        tag = 2,
+        queue = 3,
+        time = 4,
+        source = 12,
+        dest = 14,
+        data = 16,
+    );
+    assert_layout!(
+        EventBounce,
+        size = 32,
+        align = 4,
+        err = 0,
+        event = 4,
+    );
+    assert_layout!(
+        SystemInfo,
+        size = 48,
+        align = 4,
+        queues = 0,
+        clients = 4,
+        ports = 8,
+        channels = 12,
+        cur_clients = 16,
+        cur_queues = 20,
+    );
+    assert_layout!(
+        RunningInfo,
+        size = 16,
+        align = 1,
+        client = 0,
+        big_endian = 1,
+        cpu_mode = 2,
+    );
+    assert_layout!(
+        ClientInfo,
+        size = 188,
+        align = 4,
+        client = 0,
+        type_ = 4,
+        name = 8,
+        filter = 72,
+        multicast_filter = 76,
+        event_filter = 84,
+        num_ports = 116,
+        event_lost = 120,
+        card = 124,
+        pid = 128,
+        midi_version = 132,
+        group_filter = 136,
+    );
+    assert_layout!(
+        ClientPool,
+        size = 88,
+        align = 4,
+        client = 0,
+        output_pool = 4,
+        input_pool = 8,
+        output_room = 12,
+        output_free = 16,
+        input_free = 20,
+    );
+    assert_layout!(
+        RemoveEvents,
+        size = 64,
+        align = 4,
+        remove_mode = 0,
+        time = 4,
+        queue = 12,
+        dest = 13,
+        channel = 15,
+        type_ = 16,
+        tag = 20,
+    );
+    assert_layout!(
+        PortInfo,
+        size = 164,
+        align = 4,
+        addr = 0,
+        name = 2,
+        capability = 68,
+        type_ = 72,
+        midi_channels = 76,
+        midi_voices = 80,
+        synth_voices = 84,
+        read_use = 88,
+        write_use = 92,
+        kernel = 96,
+        flags = 100,
+        time_queue = 104,
+        direction = 105,
+        ump_group = 106,
+    );
+    assert_layout!(
+        QueueInfo,
+        size = 140,
+        align = 4,
+        queue = 0,
+        owner = 4,
+        name = 9,
+        flags = 76,
+    );
+    assert_layout!(
+        QueueStatus,
+        size = 92,
+        align = 4,
+        queue = 0,
+        events = 4,
+        tick = 8,
+        time = 12,
+        running = 20,
+        flags = 24,
+    );
+    assert_layout!(
+        QueueTempo,
+        size = 44,
+        align = 4,
+        queue = 0,
+        tempo = 4,
+        ppq = 8,
+        skew_value = 12,
+        skew_base = 16,
+    );
+    assert_layout!(
+        QueueTimer,
+        size = 96,
+        align = 4,
+        queue = 0,
+        type_ = 4,
+        timer = 8,
+    );
+    assert_layout!(
+        QueueTimerUnion,
+        size = 24,
+        align = 4,
+        alsa = 0,
+    );
+    assert_layout!(
+        QueueTimerAlsa,
+        size = 24,
+        align = 4,
+        id = 0,
+        resolution = 20,
+    );
+    assert_layout!(
+        QueueClient,
+        size = 76,
+        align = 4,
+        queue = 0,
+        client = 4,
+        used = 8,
+    );
+    assert_layout!(
+        PortSubscribe,
+        size = 80,
+        align = 4,
+        sender = 0,
+        dest = 2,
+        voices = 4,
+        flags = 8,
+        queue = 12,
+    );
+    assert_layout!(
+        QuerySubscribe,
+        size = 88,
+        align = 4,
+        root = 0,
+        type_ = 4,
+        index = 8,
+        num_subs = 12,
+        addr = 16,
+        queue = 18,
+        flags = 20,
+    );
+    assert_layout!(
+        ClientUmpInfo,
+        size = 520,
+        align = 1,
+        client = 0,
+        type_ = 4,
+        info = 8,
+    );
+}
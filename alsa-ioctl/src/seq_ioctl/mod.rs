@@ -1,5 +1,12 @@
+mod builder;
 mod ioctl;
+#[rustfmt::skip]
+mod layout;
+#[cfg(feature = "serde")]
+mod serde_impls;
 mod types;
+mod view;
 
 pub use ioctl::*;
 pub use types::*;
+pub use view::*;
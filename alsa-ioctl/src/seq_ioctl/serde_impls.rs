@@ -0,0 +1,133 @@
+//! Serde support, behind the `serde` feature
+//!
+//! The representation is stable:
+//! - structs are maps of their public fields with the C field names (`type`
+//!   instead of `type_`), reserved fields and kernel pointers are skipped,
+//!   missing fields deserialize to the `Default` (zero) value
+//! - `AsciiString` names are strings, invalid UTF-8 is replaced by U+FFFD
+//! - bitflags are lists of flag names as spelled in Rust (`["READ", "SUBS_READ"]`),
+//!   bits without a name are added as one hex string (`"0x400"`)
+//! - id and enum-like newtypes (`QueueId`, `ClientType`, ...) are their raw number
+
+use std::os::raw::{c_int, c_uchar, c_uint};
+
+use super::super::string::AsciiString;
+use super::types::*;
+
+serde_bitflags!(Filter: c_uint {
+    BROADCAST,
+    MULTICAST,
+    BOUNCE,
+    NO_CONVERT,
+    USE_EVENT,
+});
+
+serde_bitflags!(PortCapability: c_uint {
+    READ,
+    WRITE,
+    SYNC_READ,
+    SYNC_WRITE,
+    DUPLEX,
+    SUBS_READ,
+    SUBS_WRITE,
+    NO_EXPORT,
+    INACTIVE,
+    UMP_ENDPOINT,
+});
+
+serde_bitflags!(PortType: c_uint {
+    SPECIFIC,
+    MIDI_GENERIC,
+    MIDI_GM,
+    MIDI_GS,
+    MIDI_XG,
+    MIDI_MT32,
+    MIDI_GM2,
+    MIDI_UMP,
+    SYNTH,
+    DIRECT_SAMPLE,
+    SAMPLE,
+    HARDWARE,
+    SOFTWARE,
+    SYNTHESIZER,
+    PORT,
+    APPLICATION,
+});
+
+serde_bitflags!(PortFlags: c_uint {
+    GIVEN_PORT,
+    TIMESTAMP,
+    TIME_REAL,
+});
+
+serde_newtype!(EventType, QueueId, ClientType, MidiVersion, PortDirection);
+
+serde_struct!(Addr {
+    "client" => client: c_uchar,
+    "port" => port: c_uchar,
+});
+
+serde_struct!(Connect {
+    "sender" => sender: Addr,
+    "dest" => dest: Addr,
+});
+
+serde_struct!(EvNote {
+    "channel" => channel: c_uchar,
+    "note" => note: c_uchar,
+    "velocity" => velocity: c_uchar,
+    "off_velocity" => off_velocity: c_uchar,
+    "duration" => duration: c_uint,
+});
+
+serde_struct!(EvCtrl {
+    "channel" => channel: c_uchar,
+    "param" => param: c_uint,
+    "value" => value: c_int,
+});
+
+serde_struct!(EvResult {
+    "event" => event: c_int,
+    "result" => result: c_int,
+});
+
+serde_struct!(RealTime {
+    "tv_sec" => tv_sec: c_uint,
+    "tv_nsec" => tv_nsec: c_uint,
+});
+
+serde_struct!(QueueSkew {
+    "value" => value: c_uint,
+    "base" => base: c_uint,
+});
+
+serde_struct!(ClientInfo {
+    "client" => client: c_int,
+    "type" => type_: ClientType,
+    "name" => name: AsciiString<64>,
+    "filter" => filter: Filter,
+    "multicast_filter" => multicast_filter: [c_uchar; 8usize],
+    "event_filter" => event_filter: [c_uchar; 32usize],
+    "num_ports" => num_ports: c_int,
+    "event_lost" => event_lost: c_int,
+    "card" => card: c_int,
+    "pid" => pid: c_int,
+    "midi_version" => midi_version: MidiVersion,
+    "group_filter" => group_filter: c_uint,
+});
+
+serde_struct!(PortInfo {
+    "addr" => addr: Addr,
+    "name" => name: AsciiString<64>,
+    "capability" => capability: PortCapability,
+    "type" => type_: PortType,
+    "midi_channels" => midi_channels: c_int,
+    "midi_voices" => midi_voices: c_int,
+    "synth_voices" => synth_voices: c_int,
+    "read_use" => read_use: c_int,
+    "write_use" => write_use: c_int,
+    "flags" => flags: PortFlags,
+    "time_queue" => time_queue: QueueId,
+    "direction" => direction: PortDirection,
+    "ump_group" => ump_group: c_uchar,
+});
@@ -1,16 +1,15 @@
-use super::super::{bitfield_unit::BitfieldUnit, string::AsciiString};
+use super::super::{bitfield_unit::BitfieldUnit, string::AsciiString, version::Version};
 use bitflags::bitflags;
 use std::os::raw::{c_char, c_int, c_uchar, c_uint, c_ushort, c_void};
 
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct SndTimerId {
-    pub dev_class: c_int,
-    pub dev_sclass: c_int,
-    pub card: c_int,
-    pub device: c_int,
-    pub subdevice: c_int,
-}
+pub use super::super::timer_ioctl::SndTimerId;
+
+// #define SNDRV_SEQ_VERSION SNDRV_PROTOCOL_VERSION(1, 0, 3)
+/// Protocol version these bindings are generated from
+pub const VERSION: Version = Version::new(1, 0, 3);
+
+/// First protocol version with `user_pversion`, UMP clients and events
+pub const VERSION_UMP: Version = Version::new(1, 0, 3);
 
 //
 // definition of sequencer event types
@@ -77,6 +76,8 @@ impl EventType {
     pub const CLOCK: Self = Self(36);
     pub const TICK: Self = Self(37);
     pub const QUEUE_SKEW: Self = Self(38);
+    /// sync position changed, only defined by alsa-lib
+    pub const SYNC_POS: Self = Self(39);
 
     /* others
      * event data type = none
@@ -241,6 +242,9 @@ impl EventFlags {
     pub const PRIORITY_HIGH: u8 = 1 << 4;
     pub const PRIORITY_MASK: u8 = 1 << 4;
 
+    /// event holds a UMP packet
+    pub const UMP: u8 = 1 << 5;
+
     // prior events
     pub const fn priority_type(&self) -> u8 {
         self.0 & Self::PRIORITY_MASK
@@ -300,6 +304,11 @@ impl EventFlags {
     pub const fn is_time_relative(&self) -> bool {
         self.timemode_type() == Self::TIME_MODE_REL
     }
+
+    /// event holds a UMP packet, see `UmpEvent`
+    pub const fn is_ump(&self) -> bool {
+        self.0 & Self::UMP != 0
+    }
 }
 
 /// Note event
@@ -460,6 +469,36 @@ pub union EventData {
     pub quote: EvQuote,
 }
 
+/// (compatible) event for UMP-capable clients
+///
+/// Read and written instead of `Event` by clients with `ClientInfo::midi_version`
+/// other than `MidiVersion::LEGACY`. `data.ump` is valid when `EventFlags::UMP` is set.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct UmpEvent {
+    /// event type
+    pub type_: EventType,
+    /// event flags
+    pub flags: EventFlags,
+    pub tag: c_char,
+    /// schedule queue
+    pub queue: QueueId,
+    /// schedule time
+    pub time: Timestamp,
+    /// source address
+    pub source: Addr,
+    /// destination address
+    pub dest: Addr,
+    pub data: UmpEventData,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union UmpEventData {
+    pub data: EventData,
+    pub ump: [c_uint; 4usize],
+}
+
 /// bounce event - stored as variable size data
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -550,6 +589,8 @@ bitflags! {
         const MULTICAST = 1<<1;
         /// accept bounce event in error
         const BOUNCE = 1<<2;
+        /// don't convert UMP events
+        const NO_CONVERT = 1<<30;
         /// use event filter
         const USE_EVENT = 1<<31;
     }
@@ -578,7 +619,36 @@ pub struct ClientInfo {
     pub card: c_int,
     /// RO: pid[user]
     pub pid: c_int,
-    reserved: [c_char; 56usize],
+    /// MIDI version
+    pub midi_version: MidiVersion, // c_uint
+    /// UMP group filter bitmap
+    /// (bit 0 = groupless messages, bit 1-16 = messages for groups 1-16)
+    pub group_filter: c_uint,
+    reserved: [c_char; 48usize],
+}
+
+/// MIDI version numbers in client info
+/// Used in `ClientInfo::midi_version`
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MidiVersion(pub c_uint);
+
+impl MidiVersion {
+    /// Legacy client
+    pub const LEGACY: Self = Self(0);
+    /// UMP MIDI 1.0
+    pub const UMP_MIDI_1_0: Self = Self(1);
+    /// UMP MIDI 2.0
+    pub const UMP_MIDI_2_0: Self = Self(2);
+
+    pub fn is_legacy(&self) -> bool {
+        self == &Self::LEGACY
+    }
+
+    /// Client reads and writes `UmpEvent`
+    pub fn is_ump(&self) -> bool {
+        !self.is_legacy()
+    }
 }
 
 /// client pool size
@@ -674,6 +744,10 @@ bitflags! {
         const SUBS_WRITE = 1<<6;
         /// routing not allowed
         const NO_EXPORT = 1<<7;
+        /// inactive port
+        const INACTIVE = 1<<8;
+        /// MIDI 2.0 UMP Endpoint port
+        const UMP_ENDPOINT = 1<<9;
     }
 }
 
@@ -696,6 +770,8 @@ bitflags! {
         const MIDI_MT32 = 1<<5;
         /// General MIDI 2 compatible device
         const MIDI_GM2 = 1<<6;
+        /// UMP
+        const MIDI_UMP = 1<<7;
 
         /* other standards...*/
 
@@ -732,6 +808,19 @@ bitflags! {
     }
 }
 
+/// port usage direction
+/// Used in `PortInfo::direction`
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PortDirection(pub c_uchar);
+
+impl PortDirection {
+    pub const UNKNOWN: Self = Self(0);
+    pub const INPUT: Self = Self(1);
+    pub const OUTPUT: Self = Self(2);
+    pub const BIDIRECTION: Self = Self(3);
+}
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct PortInfo {
@@ -759,7 +848,11 @@ pub struct PortInfo {
     pub flags: PortFlags, // c_uint
     /// queue # for timestamping
     pub time_queue: QueueId,
-    reserved: [c_char; 59usize],
+    /// port usage direction (r/w/bidir)
+    pub direction: PortDirection, // c_uchar
+    /// 0 = UMP EP (no conversion), 1-16 = UMP group number
+    pub ump_group: c_uchar,
+    reserved: [c_char; 57usize],
 }
 
 bitflags! {
@@ -982,3 +1075,202 @@ pub struct QuerySubscribe {
     pub flags: SubscribeFlags, // c_uint
     reserved: [c_char; 64usize],
 }
+
+//
+// UMP-specific information
+//
+
+/// UMP groups
+pub const UMP_MAX_GROUPS: usize = 16;
+/// UMP blocks
+pub const UMP_MAX_BLOCKS: usize = 32;
+
+bitflags! {
+    /// UMP EP info flags
+    /// Used in `UmpEndpointInfo::flags`
+    #[repr(transparent)]
+    pub struct UmpEndpointFlags: c_uint {
+        const STATIC_BLOCKS = 0x01;
+    }
+}
+
+bitflags! {
+    /// UMP EP Protocol / JRTS capability bits
+    /// Used in `UmpEndpointInfo::protocol_caps` and `UmpEndpointInfo::protocol`
+    #[repr(transparent)]
+    pub struct UmpProtocol: c_uint {
+        /// MIDI 1.0
+        const MIDI1 = 0x0100;
+        /// MIDI 2.0
+        const MIDI2 = 0x0200;
+        const MIDI_MASK = 0x0300;
+        /// JRTS Transmit
+        const JRTS_TX = 0x0001;
+        /// JRTS Receive
+        const JRTS_RX = 0x0002;
+        const JRTS_MASK = 0x0003;
+    }
+}
+
+/// UMP Endpoint information
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct UmpEndpointInfo {
+    /// card number
+    pub card: c_int,
+    /// device number
+    pub device: c_int,
+    /// additional info
+    pub flags: UmpEndpointFlags, // c_uint
+    /// protocol capabilities
+    pub protocol_caps: UmpProtocol, // c_uint
+    /// current protocol
+    pub protocol: UmpProtocol, // c_uint
+    /// # of function blocks
+    pub num_blocks: c_uint,
+    /// UMP major/minor version
+    pub version: c_ushort,
+    /// MIDI device family ID
+    pub family_id: c_ushort,
+    /// MIDI family model ID
+    pub model_id: c_ushort,
+    /// MIDI manufacturer ID
+    pub manufacturer_id: c_uint,
+    /// software revision
+    pub sw_revision: [c_uchar; 4usize],
+    padding: c_ushort,
+    /// endpoint name string
+    pub name: AsciiString<128>,
+    /// unique product id string
+    pub product_id: AsciiString<128>,
+    reserved: [c_uchar; 32usize],
+}
+
+/// UMP direction
+/// Used in `UmpBlockInfo::direction`
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct UmpDirection(pub c_uchar);
+
+impl UmpDirection {
+    pub const INPUT: Self = Self(0x01);
+    pub const OUTPUT: Self = Self(0x02);
+    pub const BIDIRECTION: Self = Self(0x03);
+}
+
+bitflags! {
+    /// UMP block info flags
+    /// Used in `UmpBlockInfo::flags`
+    #[repr(transparent)]
+    pub struct UmpBlockFlags: c_uint {
+        /// MIDI 1.0 port w/o restrict
+        const IS_MIDI1 = 1<<0;
+        /// 31.25Kbps B/W MIDI1 port
+        const IS_LOWSPEED = 1<<1;
+    }
+}
+
+/// UMP block user-interface hint
+/// Used in `UmpBlockInfo::ui_hint`
+pub mod ump_ui_hint {
+    use super::*;
+
+    pub const UNKNOWN: c_uchar = 0x00;
+    pub const RECEIVER: c_uchar = 0x01;
+    pub const SENDER: c_uchar = 0x02;
+    pub const BOTH: c_uchar = 0x03;
+}
+
+/// UMP Block information
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct UmpBlockInfo {
+    /// card number
+    pub card: c_int,
+    /// device number
+    pub device: c_int,
+    /// block ID (R/W)
+    pub block_id: c_uchar,
+    /// UMP direction
+    pub direction: UmpDirection, // c_uchar
+    /// Activeness
+    pub active: c_uchar,
+    /// first group ID
+    pub first_group: c_uchar,
+    /// number of groups
+    pub num_groups: c_uchar,
+    /// MIDI-CI support version
+    pub midi_ci_version: c_uchar,
+    /// max number of sysex8 streams
+    pub sysex8_streams: c_uchar,
+    /// user interface hint
+    pub ui_hint: c_uchar,
+    /// various info flags
+    pub flags: UmpBlockFlags, // c_uint
+    /// block name string
+    pub name: AsciiString<128>,
+    reserved: [c_uchar; 32usize],
+}
+
+/// type of UMP info query
+/// Used in `ClientUmpInfo::type_`
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ClientUmpInfoType(pub c_int);
+
+impl ClientUmpInfoType {
+    pub const ENDPOINT: Self = Self(0);
+    /// first block, use `block` for the others
+    pub const BLOCK: Self = Self(1);
+
+    /// Info of the block number `block_id`
+    pub const fn block(block_id: c_uchar) -> Self {
+        Self(Self::BLOCK.0 + block_id as c_int)
+    }
+
+    pub fn is_endpoint(&self) -> bool {
+        self == &Self::ENDPOINT
+    }
+
+    pub fn is_block(&self) -> bool {
+        self.0 >= Self::BLOCK.0 && self.0 < Self::BLOCK.0 + UMP_MAX_BLOCKS as c_int
+    }
+}
+
+/// Query the UMP endpoint / block info
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub struct ClientUmpInfo {
+    /// client number to inquire/set
+    pub client: c_int,
+    /// type to inquire/set
+    pub type_: ClientUmpInfoType, // c_int
+    /// info (either UMP ep or block info)
+    pub info: [c_uchar; 512usize],
+}
+
+impl ClientUmpInfo {
+    pub fn new(client: c_int, type_: ClientUmpInfoType) -> Self {
+        Self {
+            client,
+            type_,
+            info: [0; 512],
+        }
+    }
+
+    /// `info` as endpoint info, if `type_` is `ClientUmpInfoType::ENDPOINT`
+    pub fn endpoint(&self) -> Option<UmpEndpointInfo> {
+        let type_ = self.type_;
+        type_
+            .is_endpoint()
+            .then(|| unsafe { std::ptr::read_unaligned(self.info.as_ptr().cast()) })
+    }
+
+    /// `info` as block info, if `type_` is a block
+    pub fn block(&self) -> Option<UmpBlockInfo> {
+        let type_ = self.type_;
+        type_
+            .is_block()
+            .then(|| unsafe { std::ptr::read_unaligned(self.info.as_ptr().cast()) })
+    }
+}
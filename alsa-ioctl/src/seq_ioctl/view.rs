@@ -0,0 +1,338 @@
+//! Safe typed access to the unions, with the active arm chosen by the
+//! `EventType` and the `EventFlags` of the surrounding event.
+//!
+//! The unions only hold plain old data, so reading the arm the kernel
+//! (or the `From` impls) wrote is sound. Mismatched type/data pairs read garbage,
+//! not uninitialized memory, as long as the union was zero initialized.
+
+use std::fmt;
+use std::os::raw::{c_int, c_uint};
+
+use super::types::*;
+
+/// Value of `Timestamp` chosen by `EventFlags::timestamp_type`
+#[derive(Debug, Copy, Clone)]
+pub enum EventTime {
+    Tick(TickTimeT),
+    Real(RealTime),
+}
+
+/// Value of `EvQueueControlParam` chosen by the event type
+#[derive(Debug, Copy, Clone)]
+pub enum QueueControlParam {
+    /// `START`, `CONTINUE`, `STOP`, `CLOCK`
+    None,
+    /// `TEMPO`
+    Value(c_int),
+    /// `SETPOS_TICK`
+    Tick(TickTimeT),
+    /// `SETPOS_TIME`
+    Time(RealTime),
+    /// `SYNC_POS`, `TICK`
+    Position(c_uint),
+    /// `QUEUE_SKEW`
+    Skew(QueueSkew),
+}
+
+/// Arm of `EventData` chosen by the event type and length flags
+#[derive(Debug, Copy, Clone)]
+pub enum EventDataRef<'a> {
+    /// `SYSTEM`, `RESULT`
+    Result(&'a EvResult),
+    /// `NOTE`, `NOTEON`, `NOTEOFF`, `KEYPRESS`
+    Note(&'a EvNote),
+    /// control and synchronisation messages
+    Control(&'a EvCtrl),
+    /// timer messages
+    Queue {
+        control: &'a EvQueueControl,
+        param: QueueControlParam,
+    },
+    /// client and port status messages
+    Addr(&'a Addr),
+    /// `PORT_SUBSCRIBED`, `PORT_UNSUBSCRIBED`
+    Connect(&'a Connect),
+    /// echo, OSS, fixed size user and hardware specific events
+    Raw8(&'a EvRaw8),
+    /// variable length events, the payload follows the event
+    Ext(&'a EvExt),
+    /// `KERNEL_ERROR`, `KERNEL_QUOTE`
+    Quote(&'a EvQuote),
+    /// events without data
+    None,
+}
+
+/// Arm of `UmpEventData` chosen by `EventFlags::UMP`
+#[derive(Debug, Copy, Clone)]
+pub enum UmpEventDataRef<'a> {
+    Ump(&'a [c_uint; 4usize]),
+    Data(EventDataRef<'a>),
+}
+
+impl EventType {
+    /// Event data is `EvResult`
+    pub fn is_result_type(&self) -> bool {
+        (0..5).contains(&self.0)
+    }
+
+    /// Event data is `EvNote`
+    pub fn is_note_type(&self) -> bool {
+        (5..10).contains(&self.0)
+    }
+
+    /// Event data is `EvCtrl`
+    pub fn is_control_type(&self) -> bool {
+        (10..30).contains(&self.0)
+    }
+
+    /// Event data is `EvQueueControl`
+    pub fn is_queue_type(&self) -> bool {
+        (30..40).contains(&self.0)
+    }
+
+    /// Event data is `Addr`, or `Connect` for subscription changes
+    pub fn is_message_type(&self) -> bool {
+        (60..70).contains(&self.0)
+    }
+
+    /// Event data is `Connect`
+    pub fn is_subscribe_type(&self) -> bool {
+        *self == Self::PORT_SUBSCRIBED || *self == Self::PORT_UNSUBSCRIBED
+    }
+
+    /// Fixed size user event, any data
+    pub fn is_user_type(&self) -> bool {
+        (90..100).contains(&self.0)
+    }
+
+    /// Event data is `EvExt`, `EventFlags::EVENT_LENGTH_VARIABLE` must be set
+    pub fn is_variable_type(&self) -> bool {
+        (130..140).contains(&self.0)
+    }
+
+    /// Event data is `EvQuote`
+    pub fn is_quote_type(&self) -> bool {
+        *self == Self::KERNEL_ERROR || *self == Self::KERNEL_QUOTE
+    }
+
+    /// Event carries no data
+    pub fn is_empty_type(&self) -> bool {
+        (40..50).contains(&self.0) || *self == Self::NONE
+    }
+}
+
+impl Timestamp {
+    pub fn get(&self, flags: EventFlags) -> EventTime {
+        if flags.is_time_real() {
+            EventTime::Real(unsafe { self.time })
+        } else {
+            EventTime::Tick(unsafe { self.tick })
+        }
+    }
+}
+
+impl From<EventTime> for Timestamp {
+    fn from(time: EventTime) -> Self {
+        match time {
+            EventTime::Tick(tick) => Self::tick(tick),
+            EventTime::Real(time) => Self::real(time),
+        }
+    }
+}
+
+impl EvQueueControlParam {
+    pub fn get(&self, type_: EventType) -> QueueControlParam {
+        match type_ {
+            EventType::TEMPO => QueueControlParam::Value(unsafe { self.value }),
+            EventType::SETPOS_TICK => QueueControlParam::Tick(unsafe { self.time.tick }),
+            EventType::SETPOS_TIME => QueueControlParam::Time(unsafe { self.time.time }),
+            EventType::SYNC_POS | EventType::TICK => {
+                QueueControlParam::Position(unsafe { self.position })
+            }
+            EventType::QUEUE_SKEW => QueueControlParam::Skew(unsafe { self.skew }),
+            _ => QueueControlParam::None,
+        }
+    }
+}
+
+impl EventData {
+    pub fn get(&self, type_: EventType, flags: EventFlags) -> EventDataRef<'_> {
+        if !flags.is_lenght_fixed() || type_.is_variable_type() {
+            return EventDataRef::Ext(unsafe { &self.ext });
+        }
+
+        unsafe {
+            if type_.is_empty_type() {
+                EventDataRef::None
+            } else if type_.is_result_type() {
+                EventDataRef::Result(&self.result)
+            } else if type_.is_note_type() {
+                EventDataRef::Note(&self.note)
+            } else if type_.is_control_type() {
+                EventDataRef::Control(&self.control)
+            } else if type_.is_queue_type() {
+                EventDataRef::Queue {
+                    control: &self.queue,
+                    param: self.queue.param.get(type_),
+                }
+            } else if type_.is_subscribe_type() {
+                EventDataRef::Connect(&self.connect)
+            } else if type_.is_message_type() {
+                EventDataRef::Addr(&self.addr)
+            } else if type_.is_quote_type() {
+                EventDataRef::Quote(&self.quote)
+            } else {
+                EventDataRef::Raw8(&self.raw8)
+            }
+        }
+    }
+}
+
+impl UmpEventData {
+    pub fn get(&self, type_: EventType, flags: EventFlags) -> UmpEventDataRef<'_> {
+        if flags.is_ump() {
+            UmpEventDataRef::Ump(unsafe { &self.ump })
+        } else {
+            UmpEventDataRef::Data(unsafe { self.data.get(type_, flags) })
+        }
+    }
+}
+
+impl QueueTimerUnion {
+    pub fn get(&self, type_: QueueTimerType) -> Option<&QueueTimerAlsa> {
+        type_.is_alsa().then_some(unsafe { &self.alsa })
+    }
+}
+
+impl Event {
+    pub fn time(&self) -> EventTime {
+        self.time.get(self.flags)
+    }
+
+    pub fn data(&self) -> EventDataRef<'_> {
+        self.data.get(self.type_, self.flags)
+    }
+}
+
+impl UmpEvent {
+    pub fn time(&self) -> EventTime {
+        self.time.get(self.flags)
+    }
+
+    pub fn data(&self) -> UmpEventDataRef<'_> {
+        self.data.get(self.type_, self.flags)
+    }
+}
+
+impl RemoveEvents {
+    /// `time` as selected by `RemoveMode::TIME_TICK`
+    pub fn time(&self) -> EventTime {
+        if self.remove_mode.contains(RemoveMode::TIME_TICK) {
+            EventTime::Tick(unsafe { self.time.tick })
+        } else {
+            EventTime::Real(unsafe { self.time.time })
+        }
+    }
+}
+
+impl QueueTimer {
+    pub fn alsa(&self) -> Option<&QueueTimerAlsa> {
+        self.timer.get(self.type_)
+    }
+}
+
+//
+// Debug, the unions alone do not know their active arm
+//
+
+macro_rules! opaque_debug {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl fmt::Debug for $ty {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.debug_struct(stringify!($ty)).finish_non_exhaustive()
+                }
+            }
+        )*
+    };
+}
+
+opaque_debug!(
+    Timestamp,
+    EvQueueControlParam,
+    EventData,
+    UmpEventData,
+    QueueTimerUnion
+);
+
+impl fmt::Debug for EvQueueControl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EvQueueControl")
+            .field("queue", &self.queue)
+            .field("param", &self.param)
+            .finish()
+    }
+}
+
+impl fmt::Debug for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Event")
+            .field("type_", &self.type_)
+            .field("flags", &self.flags)
+            .field("tag", &self.tag)
+            .field("queue", &self.queue)
+            .field("time", &self.time())
+            .field("source", &self.source)
+            .field("dest", &self.dest)
+            .field("data", &self.data())
+            .finish()
+    }
+}
+
+impl fmt::Debug for UmpEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UmpEvent")
+            .field("type_", &self.type_)
+            .field("flags", &self.flags)
+            .field("tag", &self.tag)
+            .field("queue", &self.queue)
+            .field("time", &self.time())
+            .field("source", &self.source)
+            .field("dest", &self.dest)
+            .field("data", &self.data())
+            .finish()
+    }
+}
+
+impl fmt::Debug for EventBounce {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventBounce")
+            .field("err", &self.err)
+            .field("event", &self.event)
+            .finish()
+    }
+}
+
+impl fmt::Debug for RemoveEvents {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RemoveEvents")
+            .field("remove_mode", &self.remove_mode)
+            .field("time", &self.time())
+            .field("queue", &self.queue)
+            .field("dest", &self.dest)
+            .field("channel", &self.channel)
+            .field("type_", &self.type_)
+            .field("tag", &self.tag)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Debug for QueueTimer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QueueTimer")
+            .field("queue", &self.queue)
+            .field("type_", &self.type_)
+            .field("alsa", &self.alsa())
+            .finish_non_exhaustive()
+    }
+}
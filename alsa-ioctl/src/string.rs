@@ -5,6 +5,18 @@ use std::{fmt::Write, os::raw::c_uchar, str::Utf8Error};
 pub struct AsciiString<const S: usize>(pub [c_uchar; S]);
 
 impl<const S: usize> AsciiString<S> {
+    /// Copy of `s`, truncated at a char boundary to leave room for the
+    /// terminating nul
+    pub fn new(s: &str) -> Self {
+        let mut string = Self([0; S]);
+        let mut len = s.len().min(S.saturating_sub(1));
+        while !s.is_char_boundary(len) {
+            len -= 1;
+        }
+        string.0[..len].copy_from_slice(&s.as_bytes()[..len]);
+        string
+    }
+
     pub fn as_str(&self) -> Result<&str, Utf8Error> {
         let i = self.0.iter().position(|v| *v == 0).unwrap_or(self.0.len());
         std::str::from_utf8(&self.0[0..i])
@@ -32,3 +44,34 @@ impl<const S: usize> std::fmt::Debug for AsciiString<S> {
         write!(f, "\"")
     }
 }
+
+/// Serialized as a string, invalid UTF-8 is replaced by U+FFFD
+///
+/// Names are set by other programs, one odd name should not fail a whole
+/// `ClientInfo`. Deserializing needs a string that fits with its nul terminator.
+#[cfg(feature = "serde")]
+impl<const S: usize> serde::Serialize for AsciiString<S> {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        let len = self.0.iter().position(|v| *v == 0).unwrap_or(self.0.len());
+        serializer.serialize_str(&String::from_utf8_lossy(&self.0[..len]))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const S: usize> serde::Deserialize<'de> for AsciiString<S> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+
+        if s.contains('\0') {
+            return Err(serde::de::Error::custom("string contains a nul byte"));
+        }
+        if s.len() >= S {
+            return Err(serde::de::Error::invalid_length(
+                s.len(),
+                &format!("at most {} bytes", S.saturating_sub(1)).as_str(),
+            ));
+        }
+
+        Ok(Self::new(&s))
+    }
+}
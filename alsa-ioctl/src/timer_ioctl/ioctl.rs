@@ -0,0 +1,84 @@
+use std::os::raw::c_int;
+
+use crate::Version;
+
+use super::types;
+
+// #define SNDRV_TIMER_IOCTL_PVERSION	_IOR('T', 0x00, int)
+ioctl_read! {
+    pversion, b'T', 0x00, Version
+}
+
+// #define SNDRV_TIMER_IOCTL_NEXT_DEVICE	_IOWR('T', 0x01, struct snd_timer_id)
+ioctl_readwrite! {
+    next_device, b'T', 0x01, types::SndTimerId
+}
+
+// #define SNDRV_TIMER_IOCTL_TREAD_OLD	_IOW('T', 0x02, int)
+// `time_t` is `c_long` here, so this is what SNDRV_TIMER_IOCTL_TREAD resolves to
+ioctl_write_ptr! {
+    tread, b'T', 0x02, c_int
+}
+
+//
+// Global timers
+//
+
+// #define SNDRV_TIMER_IOCTL_GINFO		_IOWR('T', 0x03, struct snd_timer_ginfo)
+ioctl_readwrite! {
+    ginfo, b'T', 0x03, types::TimerGInfo
+}
+
+// #define SNDRV_TIMER_IOCTL_GPARAMS	_IOW('T', 0x04, struct snd_timer_gparams)
+ioctl_write_ptr! {
+    gparams, b'T', 0x04, types::TimerGParams
+}
+
+// #define SNDRV_TIMER_IOCTL_GSTATUS	_IOWR('T', 0x05, struct snd_timer_gstatus)
+ioctl_readwrite! {
+    gstatus, b'T', 0x05, types::TimerGStatus
+}
+
+//
+// Selected timer
+//
+
+// #define SNDRV_TIMER_IOCTL_SELECT	_IOW('T', 0x10, struct snd_timer_select)
+ioctl_write_ptr! {
+    select, b'T', 0x10, types::TimerSelect
+}
+
+// #define SNDRV_TIMER_IOCTL_INFO		_IOR('T', 0x11, struct snd_timer_info)
+ioctl_read! {
+    info, b'T', 0x11, types::TimerInfo
+}
+
+// #define SNDRV_TIMER_IOCTL_PARAMS	_IOW('T', 0x12, struct snd_timer_params)
+ioctl_write_ptr! {
+    params, b'T', 0x12, types::TimerParams
+}
+
+// #define SNDRV_TIMER_IOCTL_STATUS	_IOR('T', 0x14, struct snd_timer_status)
+ioctl_read! {
+    status, b'T', 0x14, types::TimerStatus
+}
+
+// #define SNDRV_TIMER_IOCTL_START		_IO('T', 0xa0)
+ioctl_none! {
+    start, b'T', 0xa0
+}
+
+// #define SNDRV_TIMER_IOCTL_STOP		_IO('T', 0xa1)
+ioctl_none! {
+    stop, b'T', 0xa1
+}
+
+// #define SNDRV_TIMER_IOCTL_CONTINUE	_IO('T', 0xa2)
+ioctl_none! {
+    continue_, b'T', 0xa2
+}
+
+// #define SNDRV_TIMER_IOCTL_PAUSE		_IO('T', 0xa3)
+ioctl_none! {
+    pause, b'T', 0xa3
+}
@@ -0,0 +1,7 @@
+mod ioctl;
+mod read;
+mod types;
+
+pub use ioctl::*;
+pub use read::*;
+pub use types::*;
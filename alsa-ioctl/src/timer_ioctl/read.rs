@@ -0,0 +1,18 @@
+use std::os::fd::AsFd;
+
+use rustix::io::Result;
+
+use super::types::TimerTread;
+
+/// Read timer events, requires `tread(fd, 1)` to be enabled before `select`
+///
+/// Returns the number of events written into `events`.
+pub fn read_tread(fd: impl AsFd, events: &mut [TimerTread]) -> Result<usize> {
+    let len = std::mem::size_of_val(events);
+    // `TimerTread` is plain old data, every bit pattern written by the kernel is valid
+    let buff = unsafe { std::slice::from_raw_parts_mut(events.as_mut_ptr() as *mut u8, len) };
+
+    let len = rustix::io::read(fd, buff)?;
+
+    Ok(len / std::mem::size_of::<TimerTread>())
+}
@@ -0,0 +1,275 @@
+use super::super::{
+    string::AsciiString,
+    timespec::{TimePad, Timespec},
+};
+use bitflags::bitflags;
+use std::os::raw::{c_int, c_uchar, c_uint, c_ulong};
+
+/// timer classes
+/// Used in `SndTimerId::dev_class`
+pub mod class {
+    use super::*;
+
+    pub const NONE: c_int = -1;
+    pub const SLAVE: c_int = 0;
+    pub const GLOBAL: c_int = 1;
+    pub const CARD: c_int = 2;
+    pub const PCM: c_int = 3;
+}
+
+/// slave timer classes
+/// Used in `SndTimerId::dev_sclass`
+pub mod slave_class {
+    use super::*;
+
+    pub const NONE: c_int = 0;
+    pub const APPLICATION: c_int = 1;
+    /// alias
+    pub const SEQUENCER: c_int = 2;
+    /// alias
+    pub const OSS_SEQUENCER: c_int = 3;
+}
+
+/// global timers
+/// Used in `SndTimerId::device` of `class::GLOBAL` timers
+pub mod global {
+    use super::*;
+
+    pub const SYSTEM: c_int = 0;
+    /// unused
+    pub const RTC: c_int = 1;
+    pub const HPET: c_int = 2;
+    pub const HRTIMER: c_int = 3;
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SndTimerId {
+    pub dev_class: c_int,
+    pub dev_sclass: c_int,
+    pub card: c_int,
+    pub device: c_int,
+    pub subdevice: c_int,
+}
+
+impl SndTimerId {
+    /// No timer, used to start `next_device` enumeration
+    pub const NONE: Self = Self {
+        dev_class: class::NONE,
+        dev_sclass: slave_class::NONE,
+        card: -1,
+        device: -1,
+        subdevice: -1,
+    };
+
+    /// Global timer, see `global` for known devices
+    pub const fn global(device: c_int) -> Self {
+        Self {
+            dev_class: class::GLOBAL,
+            dev_sclass: slave_class::NONE,
+            card: -1,
+            device,
+            subdevice: 0,
+        }
+    }
+
+    pub fn is_none(&self) -> bool {
+        self.dev_class == class::NONE
+    }
+}
+
+bitflags! {
+    /// info flags
+    /// Used in `TimerGInfo::flags` and `TimerInfo::flags`
+    #[repr(transparent)]
+    pub struct TimerFlags: c_uint {
+        /// cannot be controlled
+        const SLAVE = 1<<0;
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct TimerGInfo {
+    /// requested timer ID
+    pub tid: SndTimerId,
+    /// timer flags - SNDRV_TIMER_FLG_*
+    pub flags: TimerFlags, // c_uint
+    /// card number
+    pub card: c_int,
+    /// timer identification
+    pub id: AsciiString<64>,
+    /// timer name
+    pub name: AsciiString<80>,
+    /// reserved for future use
+    reserved0: c_ulong,
+    /// average period resolution in ns
+    pub resolution: c_ulong,
+    /// minimal period resolution in ns
+    pub resolution_min: c_ulong,
+    /// maximal period resolution in ns
+    pub resolution_max: c_ulong,
+    /// active timer clients
+    pub clients: c_uint,
+    reserved: [c_uchar; 32usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct TimerGParams {
+    /// requested timer ID
+    pub tid: SndTimerId,
+    /// requested precise period duration (in seconds) - numerator
+    pub period_num: c_ulong,
+    /// requested precise period duration (in seconds) - denominator
+    pub period_den: c_ulong,
+    reserved: [c_uchar; 32usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct TimerGStatus {
+    /// requested timer ID
+    pub tid: SndTimerId,
+    /// current period resolution in ns
+    pub resolution: c_ulong,
+    /// precise current period resolution (in seconds) - numerator
+    pub resolution_num: c_ulong,
+    /// precise current period resolution (in seconds) - denominator
+    pub resolution_den: c_ulong,
+    reserved: [c_uchar; 32usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct TimerSelect {
+    /// bind to timer ID
+    pub id: SndTimerId,
+    reserved: [c_uchar; 32usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct TimerInfo {
+    /// timer flags - SNDRV_TIMER_FLG_*
+    pub flags: TimerFlags, // c_uint
+    /// card number
+    pub card: c_int,
+    /// timer identificator
+    pub id: AsciiString<64>,
+    /// timer name
+    pub name: AsciiString<80>,
+    /// reserved for future use
+    reserved0: c_ulong,
+    /// average period resolution in ns
+    pub resolution: c_ulong,
+    reserved: [c_uchar; 64usize],
+}
+
+bitflags! {
+    /// Used in `TimerParams::flags`
+    #[repr(transparent)]
+    pub struct TimerParamsFlags: c_uint {
+        /// auto start, otherwise one-shot
+        const AUTO = 1<<0;
+        /// exclusive use, precise start/stop/pause/continue
+        const EXCLUSIVE = 1<<1;
+        /// write early event to the poll queue
+        const EARLY_EVENT = 1<<2;
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct TimerParams {
+    /// flags - SNDRV_TIMER_PSFLG_*
+    pub flags: TimerParamsFlags, // c_uint
+    /// requested resolution in ticks
+    pub ticks: c_uint,
+    /// total size of queue (32-1024)
+    pub queue_size: c_uint,
+    /// reserved, was: failure locations
+    reserved0: c_uint,
+    /// event filter (bitmask of SNDRV_TIMER_EVENT_*)
+    pub filter: c_uint,
+    reserved: [c_uchar; 60usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct TimerStatus {
+    /// Timestamp - last update
+    pub tstamp: Timespec,
+    /// current period resolution in ns
+    pub resolution: c_uint,
+    /// counter of master tick lost
+    pub lost: c_uint,
+    /// count of read queue overruns
+    pub overrun: c_uint,
+    /// used queue size
+    pub queue: c_uint,
+    reserved: [c_uchar; 64usize],
+}
+
+/// Record read from the timer when tread mode is disabled
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct TimerRead {
+    pub resolution: c_uint,
+    pub ticks: c_uint,
+}
+
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TimerEvent(pub c_int);
+
+impl TimerEvent {
+    /// val = resolution in ns
+    pub const RESOLUTION: Self = Self(0);
+    /// val = ticks
+    pub const TICK: Self = Self(1);
+    /// val = resolution in ns
+    pub const START: Self = Self(2);
+    /// val = 0
+    pub const STOP: Self = Self(3);
+    /// val = resolution in ns
+    pub const CONTINUE: Self = Self(4);
+    /// val = 0
+    pub const PAUSE: Self = Self(5);
+    /// val = 0, early event
+    pub const EARLY: Self = Self(6);
+    /// val = 0
+    pub const SUSPEND: Self = Self(7);
+    /// val = resolution in ns
+    pub const RESUME: Self = Self(8);
+
+    /* master timer events for slave timer instances */
+    pub const MSTART: Self = Self(Self::START.0 + 10);
+    pub const MSTOP: Self = Self(Self::STOP.0 + 10);
+    pub const MCONTINUE: Self = Self(Self::CONTINUE.0 + 10);
+    pub const MPAUSE: Self = Self(Self::PAUSE.0 + 10);
+    pub const MSUSPEND: Self = Self(Self::SUSPEND.0 + 10);
+    pub const MRESUME: Self = Self(Self::RESUME.0 + 10);
+
+    /// Bit of this event in `TimerParams::filter`
+    pub const fn filter_bit(&self) -> c_uint {
+        1 << self.0
+    }
+}
+
+impl Default for TimerEvent {
+    fn default() -> Self {
+        Self::RESOLUTION
+    }
+}
+
+/// Record read from the timer when tread mode is enabled
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct TimerTread {
+    pub event: TimerEvent, // c_int
+    pad1: TimePad,
+    pub tstamp: Timespec,
+    pub val: c_uint,
+    pad2: TimePad,
+}
@@ -0,0 +1,35 @@
+use std::{
+    os::raw::{c_int, c_long, c_uchar},
+    time::Duration,
+};
+
+/// Native `time_t`
+pub type TimeT = c_long;
+
+/// `struct timespec` as used by the kernel ABI
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Timespec {
+    /// seconds
+    pub tv_sec: TimeT,
+    /// nanoseconds
+    pub tv_nsec: c_long,
+}
+
+impl Timespec {
+    pub fn as_duration(&self) -> Duration {
+        Duration::new(self.tv_sec as u64, self.tv_nsec as u32)
+    }
+}
+
+// Source:
+/*
+typedef struct { unsigned char pad[sizeof(time_t) - sizeof(int)]; } __time_pad;
+ */
+
+/// Padding used to align `timespec` after an `int`
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct TimePad {
+    pad: [c_uchar; std::mem::size_of::<TimeT>() - std::mem::size_of::<c_int>()],
+}
@@ -11,10 +11,11 @@
  */
 
 #[repr(transparent)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Version(u32);
 
 impl Version {
-    pub fn new(major: u32, minor: u32, subminor: u32) -> Self {
+    pub const fn new(major: u32, minor: u32, subminor: u32) -> Self {
         let val = ((major) << 16) | ((minor) << 8) | (subminor);
         Self(val)
     }
@@ -0,0 +1,38 @@
+use alsa_ioctl::pcm_ioctl::{HwParam, HwParams, Mask, PcmFormat, MASK_MAX};
+
+#[test]
+fn mask_out_of_range() {
+    let max = MASK_MAX as u32;
+
+    assert!(Mask::single(max).is_empty());
+    assert!(Mask::single(u32::MAX).is_empty());
+
+    let mut mask = Mask::ANY;
+    mask.reset(max);
+    mask.reset(u32::MAX);
+    assert_eq!(mask, Mask::ANY);
+
+    let mut mask = Mask::NONE;
+    mask.set(max);
+    mask.set(u32::MAX);
+    assert_eq!(mask, Mask::NONE);
+
+    mask.set(max - 1);
+    assert!(mask.test(max - 1));
+    assert_eq!(mask.iter().collect::<Vec<_>>(), [max - 1]);
+    mask.reset(max - 1);
+    assert!(mask.is_empty());
+}
+
+#[test]
+fn set_format_out_of_range() {
+    let mut params = HwParams::any();
+    params.set_format(PcmFormat(-1));
+    assert!(params.mask(HwParam::FORMAT).is_empty());
+
+    params.set_format(PcmFormat::S16_LE);
+    assert_eq!(
+        params.mask(HwParam::FORMAT).iter().collect::<Vec<_>>(),
+        [PcmFormat::S16_LE.0 as u32]
+    );
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "serde")]
+
+use alsa_ioctl::seq_ioctl::{
+    Addr, ClientInfo, ClientType, Filter, MidiVersion, PortCapability, PortDirection, PortInfo,
+    PortType, QueueId,
+};
+use serde_json::json;
+
+/// Serialize, deserialize and serialize again, both encodings have to match
+fn round_trip<T>(value: &T) -> (serde_json::Value, T)
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    let json = serde_json::to_value(value).unwrap();
+    let back: T = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&back).unwrap(), json);
+    (json, back)
+}
+
+#[test]
+fn client_info() {
+    let mut info = ClientInfo::new(128)
+        .with_name("Launchkey Mini")
+        .with_type(ClientType::USER_CLIENT)
+        .with_filter(Filter::BROADCAST | Filter::USE_EVENT)
+        .with_midi_version(MidiVersion::UMP_MIDI_2_0);
+    info.event_filter[4] = 0x20;
+    info.pid = 4242;
+
+    let (json, back) = round_trip(&info);
+    assert_eq!(json["name"], "Launchkey Mini");
+    assert_eq!(json["filter"], json!(["BROADCAST", "USE_EVENT"]));
+    assert_eq!(back.client, 128);
+    assert_eq!(back.name, info.name);
+    assert_eq!(back.filter, info.filter);
+    assert_eq!(back.event_filter, info.event_filter);
+    assert_eq!(back.pid, 4242);
+    assert_eq!(back.midi_version, MidiVersion::UMP_MIDI_2_0);
+}
+
+#[test]
+fn client_info_invalid_utf8_name() {
+    let mut info = ClientInfo::new(129);
+    info.name.0[..4].copy_from_slice(&[b'M', 0xff, b'I', 0]);
+
+    let json = serde_json::to_value(info).unwrap();
+    assert_eq!(json["name"], "M\u{fffd}I");
+}
+
+#[test]
+fn port_info() {
+    let info = PortInfo::new(Addr::new(24, 1))
+        .with_name("MIDI 1")
+        .with_capability(PortCapability::READ | PortCapability::SUBS_READ)
+        .with_type(PortType::MIDI_GENERIC | PortType::HARDWARE)
+        .with_midi_channels(16)
+        .with_direction(PortDirection::OUTPUT)
+        .with_time_queue(QueueId(2));
+
+    let (json, back) = round_trip(&info);
+    assert_eq!(json["addr"], json!({"client": 24, "port": 1}));
+    assert_eq!(back.addr, info.addr);
+    assert_eq!(back.name, info.name);
+    assert_eq!(back.capability, info.capability);
+    assert_eq!(back.type_, info.type_);
+    assert_eq!(back.midi_channels, 16);
+    assert_eq!(back.direction, PortDirection::OUTPUT);
+    assert_eq!(back.time_queue, QueueId(2));
+}
+
+#[test]
+fn bitflags() {
+    let caps = PortCapability::WRITE | PortCapability::SUBS_WRITE;
+    let (json, back) = round_trip(&caps);
+    assert_eq!(json, json!(["WRITE", "SUBS_WRITE"]));
+    assert_eq!(back, caps);
+
+    // bits without a name survive as hex
+    let caps = unsafe { PortCapability::from_bits_unchecked(0x1 | 0x400) };
+    let (json, back) = round_trip(&caps);
+    assert_eq!(json, json!(["READ", "0x400"]));
+    assert_eq!(back.bits(), 0x401);
+}
@@ -0,0 +1,19 @@
+use alsa_ioctl::string::AsciiString;
+
+#[test]
+fn new_truncates() {
+    assert_eq!(AsciiString::<4>::new("abcdef").as_str(), Ok("abc"));
+    assert_eq!(AsciiString::<4>::new("ab").as_str(), Ok("ab"));
+    assert_eq!(AsciiString::<1>::new("ab").as_str(), Ok(""));
+    assert_eq!(AsciiString::<0>::new("ab").0, []);
+}
+
+#[test]
+fn new_truncates_at_char_boundary() {
+    // 'é' is two bytes, 'あ' three, both cut by the last byte before the nul
+    assert_eq!(AsciiString::<4>::new("abé").as_str(), Ok("ab"));
+    assert_eq!(AsciiString::<4>::new("aあ").as_str(), Ok("a"));
+    assert_eq!(AsciiString::<5>::new("aあ").as_str(), Ok("aあ"));
+    assert_eq!(AsciiString::<3>::new("あい").as_str(), Ok(""));
+    assert_eq!(AsciiString::<3>::new("あい").0, [0; 3]);
+}
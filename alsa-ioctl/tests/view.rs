@@ -0,0 +1,46 @@
+use alsa_ioctl::seq_ioctl::{EvQueueControlParam, EventType, QueueControlParam};
+
+const LOW: u32 = 0x1234_5678;
+const HIGH: u32 = 0x0abc_def0;
+
+fn param() -> EvQueueControlParam {
+    EvQueueControlParam { d32: [LOW, HIGH] }
+}
+
+#[test]
+fn queue_control_param_arms() {
+    use QueueControlParam as P;
+
+    for type_ in [
+        EventType::START,
+        EventType::CONTINUE,
+        EventType::STOP,
+        EventType::CLOCK,
+    ] {
+        assert!(matches!(param().get(type_), P::None), "{:?}", type_);
+    }
+
+    assert!(matches!(param().get(EventType::TEMPO), P::Value(v) if v == LOW as i32));
+    assert!(matches!(param().get(EventType::SETPOS_TICK), P::Tick(LOW)));
+    assert!(matches!(
+        param().get(EventType::SETPOS_TIME),
+        P::Time(time) if time.tv_sec == LOW && time.tv_nsec == HIGH
+    ));
+    assert!(matches!(param().get(EventType::SYNC_POS), P::Position(LOW)));
+    assert!(matches!(param().get(EventType::TICK), P::Position(LOW)));
+    assert!(matches!(
+        param().get(EventType::QUEUE_SKEW),
+        P::Skew(skew) if skew.value == LOW && skew.base == HIGH
+    ));
+}
+
+#[test]
+fn queue_control_param_other_types() {
+    for type_ in [EventType::NOTEON, EventType::CONTROLLER, EventType::SYSEX] {
+        assert!(
+            matches!(param().get(type_), QueueControlParam::None),
+            "{:?}",
+            type_
+        );
+    }
+}
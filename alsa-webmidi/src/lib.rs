@@ -1,18 +1,22 @@
 use std::{
     collections::HashMap,
     ffi::CString,
+    io,
     sync::{Arc, Mutex},
 };
 
 use alsa_ioctl::seq_ioctl::{Addr, PortCapability, PortInfo, PortType};
 use ralsa_seq::{Port, Seq, SeqOutput};
-use rustix::event::{PollFd, PollFlags};
+use rustix::{
+    event::{PollFd, PollFlags},
+    io::Errno,
+};
 
 type OpenInputs = Arc<Mutex<HashMap<Addr, Box<dyn FnMut(ralsa_seq::event::Event) + Send>>>>;
 
 pub struct MIDIAccess {
     seq: Seq,
-    seq_input: std::thread::JoinHandle<()>,
+    seq_input: std::thread::JoinHandle<io::Result<()>>,
     _seq_output: SeqOutput,
 
     open_inputs: OpenInputs,
@@ -46,9 +50,12 @@ impl MIDIAccess {
             let mut pool_fd = [PollFd::new(&fd, PollFlags::IN)];
 
             loop {
-                rustix::event::poll(&mut pool_fd, -1).unwrap();
+                match rustix::event::poll(&mut pool_fd, -1) {
+                    Ok(_) | Err(Errno::INTR) => {}
+                    Err(err) => return Err(err.into()),
+                }
 
-                while let Some(event) = seq_input.input_event(true) {
+                while let Some(event) = seq_input.input_event(true)? {
                     if let Some(cb) = arc.lock().unwrap().get_mut(event.source()) {
                         (cb)(event);
                     }
@@ -108,8 +115,9 @@ impl MIDIAccess {
             .collect()
     }
 
-    pub fn run(self) {
-        self.seq_input.join().unwrap();
+    /// Wait for the input thread, it stops on the first failed poll or read
+    pub fn run(self) -> io::Result<()> {
+        self.seq_input.join().unwrap()
     }
 }
 
@@ -9,5 +9,5 @@ fn main() {
         });
     }
 
-    acces.run();
+    acces.run().unwrap();
 }
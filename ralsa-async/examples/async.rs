@@ -4,7 +4,7 @@ use alsa_ioctl::seq_ioctl::{PortCapability, PortType};
 
 fn main() {
     async_io::block_on(async {
-        let (seq, seq_input, seq_output) = ralsa_seq::Seq::open().unwrap();
+        let (seq, seq_input, _seq_output) = ralsa_seq::Seq::open().unwrap();
 
         let name = CString::new("input example 😀").unwrap();
         let capability = PortCapability::WRITE
@@ -12,7 +12,7 @@ fn main() {
             | PortCapability::READ
             | PortCapability::SUBS_READ;
         let kind = PortType::MIDI_GENERIC | PortType::APPLICATION;
-        let port = seq.create_simple_port(&name, capability, kind).unwrap();
+        let _port = seq.create_simple_port(&name, capability, kind).unwrap();
 
         let mut input = ralsa_async::MidiInputStream::new(seq_input);
 
@@ -17,7 +17,6 @@ impl MidiInputStream {
     pub async fn read(&mut self) -> io::Result<Option<ralsa_seq::event::Event<'_>>> {
         self.seq.readable().await?;
         let seq = unsafe { self.seq.get_mut() };
-        let event = seq.input_event(true);
-        Ok(event)
+        seq.input_event(true)
     }
 }
@@ -5,7 +5,11 @@ use std::ffi::CString;
 use ralsa_seq::event::EventWithData;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let (seq, mut seq_input, _) = ralsa_seq::Seq::open()?;
+    let (seq, mut seq_input, _) = ralsa_seq::Seq::builder()
+        .with_name("input example")
+        .with_direction(ralsa_seq::Direction::Input)
+        .with_input_pool(1000)
+        .open()?;
 
     let name = CString::new("input example")?;
     let capability = PortCapability::WRITE
@@ -20,14 +24,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     loop {
         rustix::event::poll(&mut pool_fds, -1)?;
 
-        while let Some(event) = seq_input.input_event(true) {
+        while let Some(event) = seq_input.input_event(true)? {
             dbg!(&event);
 
             match event.event_with_data() {
-                EventWithData::Qframe(_) | EventWithData::Tick { .. } | EventWithData::Clock(_) => {
+                EventWithData::Qframe(_)
+                | EventWithData::Tick { .. }
+                | EventWithData::Clock { .. } => {
                     // continue;
                 }
-                EventWithData::Sensing => {
+                EventWithData::Sensing(_) => {
                     // continue;
                 }
                 EventWithData::NoteOn(_note) => {}
@@ -35,5 +41,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 _ => {}
             }
         }
+
+        if let Some(overrun) = seq_input.take_overrun() {
+            eprintln!("{}", overrun);
+        }
     }
 }
@@ -1,7 +1,7 @@
 use std::ffi::CString;
 
 use alsa_ioctl::seq_ioctl::{PortCapability, PortType};
-use ralsa_seq::event::{Event, EventKind};
+use ralsa_seq::event::Event;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let (seq, _seq_input, mut seq_output) = ralsa_seq::Seq::open()?;
@@ -13,9 +13,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         | PortCapability::SUBS_READ;
     let kind = PortType::MIDI_GENERIC | PortType::APPLICATION;
 
-    let _port = seq.create_simple_port(&name, capability, kind)?;
+    let port = seq.create_simple_port(&name, capability, kind)?;
 
-    seq_output.send(&Event::new(EventKind::Noteon))?;
+    seq_output.send(&Event::note_on(0, 60, 100).with_source_port(port.addr().port))?;
+
+    // buffered, written together by `drain`
+    for note in [64, 67] {
+        seq_output.output(&Event::note_on(0, note, 100).with_source_port(port.addr().port))?;
+    }
+    seq_output.drain()?;
 
     Ok(())
 }
@@ -0,0 +1,183 @@
+use std::{
+    io,
+    os::raw::c_int,
+    path::{Path, PathBuf},
+};
+
+use alsa_ioctl::seq_ioctl::{self, Filter};
+use rustix::fs::OFlags;
+
+use crate::{
+    event::EventKind, find_seq_device, pool_size, DiscoveryMethod, Seq, SeqInput, SeqOutput,
+};
+
+/// Which way events flow through an opened sequencer
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Direction {
+    /// only read, `SeqOutput` writes fail
+    Input,
+    /// only write, `SeqInput` reads fail
+    Output,
+    #[default]
+    Duplex,
+}
+
+/// Options for opening a sequencer client
+///
+/// Everything but the direction and blocking mode is applied with
+/// `set_client_info` and `set_client_pool` before `open` returns.
+#[derive(Debug, Clone)]
+pub struct SeqBuilder {
+    path: Option<PathBuf>,
+    name: Option<String>,
+    direction: Direction,
+    blocking: bool,
+    input_pool: Option<usize>,
+    output_pool: Option<usize>,
+    filter: Filter,
+    event_filter: Option<[u8; 32]>,
+}
+
+impl SeqBuilder {
+    /// Non-blocking duplex client with the kernel defaults
+    pub fn new() -> Self {
+        Self {
+            path: None,
+            name: None,
+            direction: Direction::Duplex,
+            blocking: false,
+            input_pool: None,
+            output_pool: None,
+            filter: Filter::empty(),
+            event_filter: None,
+        }
+    }
+
+    /// Open this device node instead of the one found by `find_seq_device`
+    pub fn with_path(mut self, path: impl AsRef<Path>) -> Self {
+        self.path = Some(path.as_ref().to_owned());
+        self
+    }
+
+    /// Client name shown to other clients, instead of `Client-NNN`
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Block in reads and writes instead of failing with `WouldBlock`
+    pub fn with_blocking(mut self, blocking: bool) -> Self {
+        self.blocking = blocking;
+        self
+    }
+
+    /// Kernel input pool size, in events
+    ///
+    /// `open` fails with `InvalidInput` if it does not fit a `c_int`.
+    pub fn with_input_pool(mut self, size: usize) -> Self {
+        self.input_pool = Some(size);
+        self
+    }
+
+    /// Kernel output pool size, in events
+    ///
+    /// `open` fails with `InvalidInput` if it does not fit a `c_int`.
+    pub fn with_output_pool(mut self, size: usize) -> Self {
+        self.output_pool = Some(size);
+        self
+    }
+
+    /// Receive events sent to `Addr::BROADCAST` clients
+    pub fn with_broadcast(mut self, enable: bool) -> Self {
+        self.filter.set(Filter::BROADCAST, enable);
+        self
+    }
+
+    /// Receive multicast events
+    pub fn with_multicast(mut self, enable: bool) -> Self {
+        self.filter.set(Filter::MULTICAST, enable);
+        self
+    }
+
+    /// Only let these event types into the input pool
+    pub fn with_event_filter(mut self, kinds: impl IntoIterator<Item = EventKind>) -> Self {
+        self.event_filter = Some(event_filter_bitmap(kinds));
+        self
+    }
+
+    pub fn open(&self) -> io::Result<(Seq, SeqInput, SeqOutput)> {
+        let (path, discovery) = match &self.path {
+            Some(path) => (path.clone(), DiscoveryMethod::Path),
+            None => {
+                let device = find_seq_device()?;
+                (device.path, device.method)
+            }
+        };
+
+        let mut flags = match self.direction {
+            Direction::Input => OFlags::RDONLY,
+            Direction::Output => OFlags::WRONLY,
+            Direction::Duplex => OFlags::RDWR,
+        };
+        if !self.blocking {
+            flags |= OFlags::NONBLOCK;
+        }
+
+        let (seq, input, output) = Seq::open_device(&path, discovery, flags)?;
+        self.configure(&seq)?;
+
+        Ok((seq, input, output))
+    }
+
+    fn configure(&self, seq: &Seq) -> io::Result<()> {
+        if self.name.is_some() || !self.filter.is_empty() || self.event_filter.is_some() {
+            let mut info = seq_ioctl::ClientInfo::new(seq.inner.client_id as c_int);
+            seq_ioctl::get_client_info(seq, &mut info)?;
+
+            if let Some(name) = &self.name {
+                info = info.with_name(name);
+            }
+            info.filter |= self.filter;
+            if let Some(event_filter) = self.event_filter {
+                info = info.with_event_filter(event_filter);
+                info.filter |= Filter::USE_EVENT;
+            }
+
+            seq_ioctl::set_client_info(seq, info)?;
+        }
+
+        if self.input_pool.is_some() || self.output_pool.is_some() {
+            let mut pool = seq.client_pool()?;
+            if let Some(size) = self.input_pool {
+                pool.input_pool = pool_size(size)?;
+            }
+            if let Some(size) = self.output_pool {
+                pool.output_pool = pool_size(size)?;
+            }
+            seq.set_client_pool(pool)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for SeqBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `ClientInfo::event_filter` letting only `kinds` through
+pub(crate) fn event_filter_bitmap(kinds: impl IntoIterator<Item = EventKind>) -> [u8; 32] {
+    let mut bitmap = [0; 32];
+    for kind in kinds {
+        let type_ = seq_ioctl::EventType::from(kind).0 as usize;
+        bitmap[type_ / 8] |= 1 << (type_ % 8);
+    }
+    bitmap
+}
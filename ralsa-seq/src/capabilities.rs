@@ -0,0 +1,81 @@
+use std::{fmt, io, os::fd::AsFd};
+
+use alsa_ioctl::{seq_ioctl, Version};
+
+/// The kernel speaks a sequencer protocol these bindings can't talk to
+#[derive(Debug, Clone, Copy)]
+pub struct IncompatibleVersion {
+    pub kernel: Version,
+    pub header: Version,
+}
+
+impl fmt::Display for IncompatibleVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "incompatible sequencer protocol: kernel {}.{}.{}, ralsa-seq built for {}.{}.{}",
+            self.kernel.major(),
+            self.kernel.minor(),
+            self.kernel.subminor(),
+            self.header.major(),
+            self.header.minor(),
+            self.header.subminor(),
+        )
+    }
+}
+
+impl std::error::Error for IncompatibleVersion {}
+
+impl From<IncompatibleVersion> for io::Error {
+    fn from(err: IncompatibleVersion) -> Self {
+        io::Error::new(io::ErrorKind::Unsupported, err)
+    }
+}
+
+/// What the sequencer behind a `Seq` supports, detected on open
+#[derive(Debug, Clone, Copy)]
+pub struct Capabilities {
+    /// protocol version reported by the kernel
+    pub kernel_version: Version,
+    /// protocol version announced with `USER_PVERSION`, `None` on older kernels
+    pub client_version: Option<Version>,
+    /// UMP clients and events can be used
+    ///
+    /// Setting a UMP `midi_version` may still be refused by kernels built
+    /// without UMP support.
+    pub ump: bool,
+    /// system limits and usage at open time
+    pub system: seq_ioctl::SystemInfo,
+}
+
+impl Capabilities {
+    /// Check the kernel protocol and announce ours to it
+    pub(crate) fn negotiate<Fd: AsFd>(fd: Fd) -> io::Result<Self> {
+        let kernel_version = seq_ioctl::pversion(&fd)?;
+
+        if kernel_version.check_protocol_incompatible(seq_ioctl::VERSION) {
+            return Err(IncompatibleVersion {
+                kernel: kernel_version,
+                header: seq_ioctl::VERSION,
+            }
+            .into());
+        }
+
+        let client_version = if kernel_version >= seq_ioctl::VERSION_UMP {
+            seq_ioctl::user_pversion(&fd, seq_ioctl::VERSION)?;
+            Some(seq_ioctl::VERSION)
+        } else {
+            None
+        };
+
+        let mut system = seq_ioctl::SystemInfo::default();
+        seq_ioctl::system_info(&fd, &mut system)?;
+
+        Ok(Self {
+            kernel_version,
+            client_version,
+            ump: client_version.is_some_and(|v| v >= seq_ioctl::VERSION_UMP),
+            system,
+        })
+    }
+}
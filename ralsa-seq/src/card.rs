@@ -0,0 +1,204 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    os::raw::c_int,
+    path::{Path, PathBuf},
+};
+
+use alsa_ioctl::seq_ioctl;
+
+use crate::{proc_asound::ProcAsound, Seq};
+
+/// Sound card with its physical identity and sequencer clients
+#[derive(Debug, Clone)]
+pub struct Card {
+    /// card number, as in `ClientInfo::card`
+    pub index: c_int,
+    /// short name, e.g. `Launchkey Mini`
+    pub name: String,
+    pub long_name: String,
+    /// e.g. `Focusrite-Novation`, from udev
+    pub vendor: Option<String>,
+    /// hex USB vendor id, from udev
+    pub vendor_id: Option<String>,
+    /// from udev
+    pub model: Option<String>,
+    /// hex USB product id, from udev
+    pub model_id: Option<String>,
+    /// from udev
+    pub serial: Option<String>,
+    /// bus of the parent device, e.g. `usb` or `pci`
+    pub bus: Option<String>,
+    /// position on the bus, e.g. `1-2` for a USB port or `0000:00:1f.3`
+    pub location: Option<String>,
+    /// persistent path like `pci-0000:00:14.0-usb-0:2:1.0`, from udev
+    pub path: Option<String>,
+    /// kernel sequencer clients of this card
+    pub clients: Vec<seq_ioctl::ClientInfo>,
+}
+
+impl Card {
+    pub(crate) fn new(index: c_int) -> Self {
+        Self {
+            index,
+            name: String::new(),
+            long_name: String::new(),
+            vendor: None,
+            vendor_id: None,
+            model: None,
+            model_id: None,
+            serial: None,
+            bus: None,
+            location: None,
+            path: None,
+            clients: Vec::new(),
+        }
+    }
+
+    /// Name for users, e.g. `Launchkey Mini (USB 1-2)`
+    pub fn label(&self) -> String {
+        let name = match (&self.name, &self.model) {
+            (name, _) if !name.is_empty() => name.as_str(),
+            (_, Some(model)) => model.as_str(),
+            _ => "Card",
+        };
+
+        match (&self.bus, &self.location) {
+            (Some(bus), Some(location)) => {
+                format!("{} ({} {})", name, bus.to_uppercase(), location)
+            }
+            _ => format!("{} (card {})", name, self.index),
+        }
+    }
+
+    /// Whether `client` belongs to this card
+    pub fn has_client(&self, client: c_int) -> bool {
+        self.clients.iter().any(|info| info.client == client)
+    }
+}
+
+impl Seq {
+    /// All sound cards, with their kernel sequencer clients
+    ///
+    /// Names come from `/proc/asound/cards`, bus and location from sysfs and
+    /// vendor, model, serial and path from udev when the `udev` feature is
+    /// enabled. Missing sources leave the fields empty.
+    pub fn cards(&self) -> io::Result<Vec<Card>> {
+        let mut cards = BTreeMap::new();
+
+        match ProcAsound::default().cards() {
+            Ok(proc_cards) => {
+                for proc_card in proc_cards {
+                    let card = cards
+                        .entry(proc_card.index)
+                        .or_insert_with(|| Card::new(proc_card.index));
+                    card.name = proc_card.name;
+                    card.long_name = proc_card.long_name;
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+
+        #[cfg(feature = "udev")]
+        query_udev(&mut cards)?;
+
+        for card in cards.values_mut() {
+            let device = PathBuf::from(format!("/sys/class/sound/card{}/device", card.index));
+            read_sysfs(card, &device);
+        }
+
+        for client in self.clients_iter() {
+            if let Some(card) = cards.get_mut(&client.card) {
+                card.clients.push(client);
+            }
+        }
+
+        Ok(cards.into_values().collect())
+    }
+}
+
+#[cfg(feature = "udev")]
+fn query_udev(cards: &mut BTreeMap<c_int, Card>) -> io::Result<()> {
+    let mut enumerator = udev::Enumerator::new()?;
+    enumerator.match_subsystem("sound")?;
+    enumerator.match_sysname("card[0-9]*")?;
+
+    for device in enumerator.scan_devices()? {
+        let Some(index) = device.sysnum() else {
+            continue;
+        };
+        let property = |name: &str| {
+            device
+                .property_value(name)
+                .map(|value| value.to_string_lossy().into_owned())
+        };
+
+        let card = cards
+            .entry(index as c_int)
+            .or_insert_with(|| Card::new(index as c_int));
+        card.vendor = property("ID_VENDOR_ENC")
+            .map(|value| decode_udev_enc(&value))
+            .or_else(|| property("ID_VENDOR_FROM_DATABASE"));
+        card.vendor_id = property("ID_VENDOR_ID");
+        card.model = property("ID_MODEL_ENC")
+            .map(|value| decode_udev_enc(&value))
+            .or_else(|| property("ID_MODEL_FROM_DATABASE"));
+        card.model_id = property("ID_MODEL_ID");
+        card.serial = property("ID_SERIAL_SHORT");
+        card.bus = property("ID_BUS");
+        card.path = property("ID_PATH");
+    }
+
+    Ok(())
+}
+
+/// Undo the `\xNN` escaping of udev `*_ENC` properties
+#[cfg(feature = "udev")]
+fn decode_udev_enc(value: &str) -> String {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut rest = value.as_bytes();
+
+    while let Some((&byte, tail)) = rest.split_first() {
+        let escaped = tail
+            .strip_prefix(b"x")
+            .filter(|_| byte == b'\\')
+            .and_then(|hex| std::str::from_utf8(hex.get(..2)?).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        match escaped {
+            Some(escaped) => {
+                bytes.push(escaped);
+                rest = &tail[3..];
+            }
+            None => {
+                bytes.push(byte);
+                rest = tail;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).trim().to_owned()
+}
+
+/// Bus and location of the parent device, kept if udev already knew better
+fn read_sysfs(card: &mut Card, device: &Path) {
+    let Ok(device) = fs::canonicalize(device) else {
+        return;
+    };
+    let file_name = |path: &Path| Some(path.file_name()?.to_string_lossy().into_owned());
+
+    let bus = fs::read_link(device.join("subsystem"))
+        .ok()
+        .and_then(|subsystem| file_name(&subsystem));
+
+    // sound cards bind to a USB interface like `1-2:1.0`, the port is its parent
+    let location = if bus.as_deref() == Some("usb") {
+        device.parent().and_then(file_name)
+    } else {
+        file_name(&device)
+    };
+
+    card.bus = card.bus.take().or(bus);
+    card.location = location;
+}
@@ -0,0 +1,114 @@
+use std::{io, os::raw::c_int};
+
+use alsa_ioctl::seq_ioctl;
+
+use crate::{PortIter, Seq};
+
+/// Who runs a client
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClientKind {
+    /// application with the sequencer device open
+    User,
+    /// driver, like the clients of a sound card
+    Kernel,
+}
+
+/// Sequencer client, as reported by `get_client_info`
+#[derive(Debug, Clone)]
+pub struct Client {
+    info: seq_ioctl::ClientInfo,
+    name: String,
+}
+
+impl Client {
+    pub fn id(&self) -> u8 {
+        self.info.client as u8
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> ClientKind {
+        if self.info.type_.is_kernel_client() {
+            ClientKind::Kernel
+        } else {
+            ClientKind::User
+        }
+    }
+
+    /// Sound card of a kernel client
+    pub fn card(&self) -> Option<c_int> {
+        (self.info.card >= 0).then_some(self.info.card)
+    }
+
+    /// Process of a user client
+    pub fn pid(&self) -> Option<c_int> {
+        (self.info.pid >= 0).then_some(self.info.pid)
+    }
+
+    pub fn num_ports(&self) -> usize {
+        self.info.num_ports.max(0) as usize
+    }
+
+    /// Errors the kernel failed to bounce back to the client
+    ///
+    /// Only counted for clients with `Filter::BOUNCE` set. Events dropped by
+    /// an input pool overflow are not counted.
+    pub fn events_lost(&self) -> u32 {
+        self.info.event_lost as u32
+    }
+
+    pub fn info(&self) -> &seq_ioctl::ClientInfo {
+        &self.info
+    }
+
+    /// Ports of this client
+    pub fn ports(&self, seq: &Seq) -> PortIter {
+        seq.ports_iter(self.id())
+    }
+}
+
+impl From<seq_ioctl::ClientInfo> for Client {
+    fn from(info: seq_ioctl::ClientInfo) -> Self {
+        // names are set by other clients, which may not stick to ASCII
+        let bytes = &info.name.0;
+        let end = bytes.iter().position(|v| *v == 0).unwrap_or(bytes.len());
+        let name = String::from_utf8_lossy(&bytes[..end]).into_owned();
+
+        Self { info, name }
+    }
+}
+
+impl Seq {
+    /// Client number of this `Seq`
+    pub fn client_id(&self) -> u8 {
+        self.inner.client_id as u8
+    }
+
+    /// This client
+    pub fn client(&self) -> io::Result<Client> {
+        self.client_info(self.client_id())
+    }
+
+    /// All clients, in id order
+    pub fn clients(&self) -> impl Iterator<Item = Client> {
+        self.clients_iter().map(Client::from)
+    }
+
+    pub fn client_info(&self, id: u8) -> io::Result<Client> {
+        let mut info = seq_ioctl::ClientInfo::new(id as c_int);
+        seq_ioctl::get_client_info(self, &mut info)?;
+
+        Ok(Client::from(info))
+    }
+
+    /// Rename this client, names longer than 63 bytes are cut short
+    pub fn set_client_name(&self, name: &str) -> io::Result<()> {
+        let mut info = seq_ioctl::ClientInfo::new(self.inner.client_id as c_int);
+        seq_ioctl::get_client_info(self, &mut info)?;
+        seq_ioctl::set_client_info(self, info.with_name(name))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use rustix::fs::{major, minor, stat, FileType};
+
+/// How the sequencer device node was found
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscoveryMethod {
+    /// `udev` enumeration of the `sound` subsystem
+    Udev,
+    /// device number from `/sys/class/sound/seq/dev` matched against `/dev/snd`
+    Sysfs,
+    /// path given to `Seq::open_path` or `SeqBuilder::with_path`
+    Path,
+}
+
+/// Sequencer device node and how it was found
+#[derive(Debug, Clone)]
+pub struct SeqDevice {
+    pub path: PathBuf,
+    pub method: DiscoveryMethod,
+}
+
+/// Find the sequencer device node
+///
+/// Tries udev first when the `udev` feature is enabled and falls back to sysfs,
+/// which also works in containers without a udev database.
+pub fn find_seq_device() -> io::Result<SeqDevice> {
+    #[cfg(feature = "udev")]
+    if let Ok(path) = query_udev() {
+        return Ok(SeqDevice {
+            path,
+            method: DiscoveryMethod::Udev,
+        });
+    }
+
+    query_sysfs("/sys/class/sound/seq/dev", "/dev/snd").map(|path| SeqDevice {
+        path,
+        method: DiscoveryMethod::Sysfs,
+    })
+}
+
+#[cfg(feature = "udev")]
+fn query_udev() -> io::Result<PathBuf> {
+    let mut enumerator = udev::Enumerator::new()?;
+    enumerator.match_subsystem("sound")?;
+    enumerator.match_sysname("seq")?;
+
+    enumerator
+        .scan_devices()?
+        .next()
+        .and_then(|dev| dev.devnode().map(ToOwned::to_owned))
+        .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+}
+
+/// Look up the character device with the `major:minor` number in `dev_file`
+///
+/// The sysfs fallback of `find_seq_device`, which reads
+/// `/sys/class/sound/seq/dev` and searches `/dev/snd`. Fails with
+/// `InvalidData` for a malformed number and `NotFound` without a match.
+pub fn query_sysfs<P, Q>(dev_file: P, dev_dir: Q) -> io::Result<PathBuf>
+where
+    P: AsRef<Path>,
+    Q: AsRef<Path>,
+{
+    let dev = fs::read_to_string(dev_file)?;
+    let (dev_major, dev_minor) = dev
+        .trim()
+        .split_once(':')
+        .and_then(|(major, minor)| Some((major.parse().ok()?, minor.parse().ok()?)))
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "malformed device number"))?;
+
+    for entry in fs::read_dir(dev_dir)? {
+        let path = entry?.path();
+        let Ok(st) = stat(&path) else {
+            continue;
+        };
+
+        if FileType::from_raw_mode(st.st_mode) == FileType::CharacterDevice
+            && major(st.st_rdev) == dev_major
+            && minor(st.st_rdev) == dev_minor
+        {
+            return Ok(path);
+        }
+    }
+
+    Err(io::Error::from(io::ErrorKind::NotFound))
+}
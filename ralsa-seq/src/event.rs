@@ -1,4 +1,4 @@
-use std::{borrow::Cow, time::Duration};
+use std::{borrow::Cow, io, time::Duration};
 
 use alsa_ioctl::seq_ioctl::QueueSkew;
 pub use alsa_ioctl::seq_ioctl::{
@@ -13,34 +13,307 @@ pub struct Event<'a> {
 }
 
 impl<'a> Event<'a> {
+    /// Direct event to the subscribers of port 0 with empty data
+    ///
+    /// The source client is filled in by `SeqOutput` when the event is sent.
     pub fn new(kind: EventKind) -> Self {
-        let mut raw: seq_ioctl::Event = unsafe { std::mem::zeroed() };
-        raw.type_ = kind.into();
-        raw.data = seq_ioctl::EventData {
-            note: EvNote {
-                channel: 0,
-                note: 50,
-                velocity: 255,
-                off_velocity: 0,
-                duration: 100,
-            },
+        let event = Self {
+            kind,
+            raw: Cow::Owned(seq_ioctl::Event::new(kind.into())),
+            raw_extra: Cow::Owned(Vec::new()),
         };
-        raw.source = Addr {
-            client: 128,
-            port: 0,
+
+        if seq_ioctl::EventType::from(kind).is_variable_type() {
+            event.with_ext(Vec::new())
+        } else {
+            event
+        }
+    }
+
+    fn with_fixed_data(kind: EventKind, data: impl Into<seq_ioctl::EventData>) -> Self {
+        Self::new(kind).with_data(data)
+    }
+
+    /// Note on, a velocity of 0 is a note off for most receivers
+    pub fn note_on(channel: u8, note: u8, velocity: u8) -> Self {
+        Self::with_fixed_data(EventKind::Noteon, EvNote::new(channel, note, velocity))
+    }
+
+    pub fn note_off(channel: u8, note: u8, velocity: u8) -> Self {
+        Self::with_fixed_data(EventKind::Noteoff, EvNote::new(channel, note, velocity))
+    }
+
+    /// Note on followed by a note off after `duration` of the queue time
+    pub fn note(channel: u8, note: u8, velocity: u8, duration: u32) -> Self {
+        let data = EvNote::new(channel, note, velocity).with_duration(duration);
+        Self::with_fixed_data(EventKind::Note, data)
+    }
+
+    /// Polyphonic key pressure
+    pub fn key_pressure(channel: u8, note: u8, pressure: u8) -> Self {
+        Self::with_fixed_data(EventKind::Keypress, EvNote::new(channel, note, pressure))
+    }
+
+    pub fn controller(channel: u8, param: u32, value: i32) -> Self {
+        Self::with_fixed_data(EventKind::Controller, EvCtrl::new(channel, param, value))
+    }
+
+    pub fn program_change(channel: u8, program: i32) -> Self {
+        Self::with_fixed_data(EventKind::Pgmchange, EvCtrl::new(channel, 0, program))
+    }
+
+    pub fn channel_pressure(channel: u8, pressure: i32) -> Self {
+        Self::with_fixed_data(EventKind::Chanpress, EvCtrl::new(channel, 0, pressure))
+    }
+
+    /// Pitch bend from -8192 to 8191, 0 is the center
+    pub fn pitchbend(channel: u8, value: i32) -> Self {
+        Self::with_fixed_data(EventKind::Pitchbend, EvCtrl::new(channel, 0, value))
+    }
+
+    /// System exclusive message, `bytes` includes the `F0` and `F7` framing
+    pub fn sysex(bytes: impl Into<Cow<'a, [u8]>>) -> Self {
+        Self::new(EventKind::Sysex).with_ext(bytes)
+    }
+
+    /// Source port of this client
+    pub fn with_source_port(mut self, port: u8) -> Self {
+        self.raw.to_mut().source.port = port;
+        self
+    }
+
+    pub fn with_dest(mut self, dest: Addr) -> Self {
+        self.raw.to_mut().dest = dest;
+        self
+    }
+
+    /// Queue used to schedule the event, `QueueId::DIRECT` to send it right away
+    pub fn with_queue(mut self, queue: QueueId) -> Self {
+        self.raw.to_mut().queue = queue;
+        self
+    }
+
+    /// Absolute schedule time on the queue
+    pub fn with_time(self, time: EventTime) -> Self {
+        self.with_time_mode(time, seq_ioctl::EventFlags::TIME_MODE_ABS)
+    }
+
+    /// Schedule time relative to the current queue time
+    pub fn with_relative_time(self, time: EventTime) -> Self {
+        self.with_time_mode(time, seq_ioctl::EventFlags::TIME_MODE_REL)
+    }
+
+    fn with_time_mode(mut self, time: EventTime, mode: u8) -> Self {
+        let raw = self.raw.to_mut();
+        let (time, stamp) = match time {
+            EventTime::Tick(tick) => (
+                seq_ioctl::Timestamp::tick(tick),
+                seq_ioctl::EventFlags::TIME_STAMP_TICK,
+            ),
+            EventTime::Time(time) => (
+                seq_ioctl::Timestamp::real(seq_ioctl::RealTime::new(
+                    time.as_secs() as u32,
+                    time.subsec_nanos(),
+                )),
+                seq_ioctl::EventFlags::TIME_STAMP_REAL,
+            ),
         };
-        raw.dest = Addr {
-            // client: 28,
-            // port: 0,
-            client: seq_ioctl::address::SUBSCRIBERS,
-            port: seq_ioctl::address::UNKNOWN,
+
+        let mask = seq_ioctl::EventFlags::TIME_STAMP_MASK | seq_ioctl::EventFlags::TIME_MODE_MASK;
+        raw.flags.0 = (raw.flags.0 & !mask) | stamp | mode;
+        raw.time = time;
+        self
+    }
+
+    pub fn with_tag(mut self, tag: i8) -> Self {
+        self.raw.to_mut().tag = tag;
+        self
+    }
+
+    /// Deliver before other events scheduled at the same time
+    pub fn with_priority_high(mut self, high: bool) -> Self {
+        let flags = &mut self.raw.to_mut().flags;
+        flags.0 &= !seq_ioctl::EventFlags::PRIORITY_MASK;
+        if high {
+            flags.0 |= seq_ioctl::EventFlags::PRIORITY_HIGH;
+        }
+        self
+    }
+
+    /// Fixed size data, has to match the kind of the event
+    pub fn with_data(mut self, data: impl Into<seq_ioctl::EventData>) -> Self {
+        let raw = self.raw.to_mut();
+        raw.flags.0 &= !seq_ioctl::EventFlags::EVENT_LENGTH_MASK;
+        raw.data = data.into();
+        self.raw_extra = Cow::Owned(Vec::new());
+        self
+    }
+
+    /// Variable length data for `Sysex`, `Bounce` and `UsrVar*` events
+    pub fn with_ext(mut self, bytes: impl Into<Cow<'a, [u8]>>) -> Self {
+        let bytes = bytes.into();
+        let raw = self.raw.to_mut();
+        raw.flags.0 = (raw.flags.0 & !seq_ioctl::EventFlags::EVENT_LENGTH_MASK)
+            | seq_ioctl::EventFlags::EVENT_LENGTH_VARIABLE;
+        raw.data = EvExt {
+            len: bytes.len() as u32,
+            ptr: std::ptr::null_mut(),
+        }
+        .into();
+        self.raw_extra = bytes;
+        self
+    }
+
+    /// Replace kind and data, keeping the header (addresses, queue, time, ...)
+    pub fn with_event_data(mut self, data: EventWithData<'a>) -> Self {
+        use EventWithData as E;
+        use QueueControlEventData as Q;
+
+        self.kind = data.kind();
+        self.raw.to_mut().type_ = match data {
+            E::Unknown { type_, .. } => seq_ioctl::EventType(type_),
+            _ => self.kind.into(),
         };
-        raw.queue = QueueId::DIRECT;
 
-        Self {
-            kind,
-            raw: Cow::Owned(raw),
-            raw_extra: Cow::Owned(Vec::new()),
+        let queue = |queue, data: Q| EvQueueControl::new(queue, data.into());
+
+        match data {
+            E::System(result) | E::Result(result) => self.with_data(result),
+
+            E::Note(note) | E::NoteOn(note) | E::NoteOff(note) | E::KeyPress(note) => {
+                self.with_data(note)
+            }
+
+            E::Controller(ctrl)
+            | E::Pgmchange(ctrl)
+            | E::Chanpress(ctrl)
+            | E::Pitchbend(ctrl)
+            | E::Control14(ctrl)
+            | E::Nonregparam(ctrl)
+            | E::Regparam(ctrl)
+            | E::Songpos(ctrl)
+            | E::Songsel(ctrl)
+            | E::Qframe(ctrl)
+            | E::Timesign(ctrl)
+            | E::Keysign(ctrl) => self.with_data(ctrl),
+
+            E::Start { queue: q, param }
+            | E::Continue { queue: q, param }
+            | E::Stop { queue: q, param }
+            | E::Clock { queue: q, param } => self.with_data(EvQueueControl::new(
+                q,
+                seq_ioctl::EvQueueControlParam { d32: param },
+            )),
+            E::SetposTick { queue: q, position }
+            | E::Tick { queue: q, position }
+            | E::SyncPos { queue: q, position } => self.with_data(queue(q, Q::Position(position))),
+            E::SetposTime { queue: q, position } => self.with_data(queue(q, Q::Time(position))),
+            E::Tempo { queue: q, value } => self.with_data(queue(q, Q::Value(value))),
+            E::QueueSkew { queue: q, skew } => self.with_data(queue(q, Q::Skew(skew))),
+
+            E::TuneRequest(d)
+            | E::Reset(d)
+            | E::Sensing(d)
+            | E::Echo(d)
+            | E::Oss(d)
+            | E::Usr0(d)
+            | E::Usr1(d)
+            | E::Usr2(d)
+            | E::Usr3(d)
+            | E::Usr4(d)
+            | E::Usr5(d)
+            | E::Usr6(d)
+            | E::Usr7(d)
+            | E::Usr8(d)
+            | E::Usr9(d)
+            | E::None(d)
+            | E::Unknown {
+                data: RawEventData::Fixed(d),
+                ..
+            } => self.with_data(seq_ioctl::EvRaw8 { d }),
+
+            E::ClientStart(addr)
+            | E::ClientExit(addr)
+            | E::ClientChange(addr)
+            | E::PortStart(addr)
+            | E::PortExit(addr)
+            | E::PortChange(addr) => self.with_data(addr),
+
+            E::PortSubscribed(connect) | E::PortUnsubscribed(connect) => self.with_data(connect),
+
+            E::Sysex(bytes)
+            | E::Bounce(bytes)
+            | E::UsrVar0(bytes)
+            | E::UsrVar1(bytes)
+            | E::UsrVar2(bytes)
+            | E::UsrVar3(bytes)
+            | E::UsrVar4(bytes)
+            | E::Unknown {
+                data: RawEventData::Variable(bytes),
+                ..
+            } => self.with_ext(bytes),
+
+            E::KernelError(quote) | E::KernelQuote(quote) => self.with_data(quote),
+        }
+    }
+
+    /// Decode the failed event and its error of a `Bounce` event
+    ///
+    /// `None` for other kinds, an `InvalidData` error if the payload is too
+    /// short for the bounced event or its error is not an errno.
+    pub fn bounced(&self) -> Option<io::Result<BouncedEvent<'_>>> {
+        let EventWithData::Bounce(payload) = self.event_with_data() else {
+            return None;
+        };
+
+        let header = std::mem::size_of::<seq_ioctl::EventBounce>();
+        if payload.len() < header {
+            return Some(Err(truncated("bounce event")));
+        }
+        // the payload is not aligned for `EventBounce`
+        let bounce: seq_ioctl::EventBounce =
+            unsafe { std::ptr::read_unaligned(payload.as_ptr() as *const _) };
+        let rest = &payload[header..];
+
+        let original = Event {
+            kind: EventKind::from(bounce.event.type_),
+            raw: Cow::Owned(bounce.event),
+            raw_extra: Cow::Borrowed(&[]),
+        };
+        let original = match original.raw.data() {
+            seq_ioctl::EventDataRef::Ext(ext) => match rest.get(..ext.len as usize) {
+                Some(bytes) => Event {
+                    raw_extra: Cow::Borrowed(bytes),
+                    ..original
+                },
+                None => return Some(Err(truncated("bounce event"))),
+            },
+            _ => original,
+        };
+
+        // any client can send a `Bounce`, the error is not trusted
+        let Some(error) = errno(bounce.err.unsigned_abs()) else {
+            return Some(Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid bounce error",
+            )));
+        };
+
+        Some(Ok(BouncedEvent { error, original }))
+    }
+
+    /// Quote of a `KernelError` or `KernelQuote` event
+    pub fn quote(&self) -> Option<QuotedEvent> {
+        match self.event_with_data() {
+            EventWithData::KernelError(quote) | EventWithData::KernelQuote(quote) => Some(quote),
+            _ => None,
+        }
+    }
+
+    /// Fill in the sending client
+    pub(crate) fn set_source_client(&mut self, client: u8) {
+        if self.raw.source.client != client {
+            self.raw.to_mut().source.client = client;
         }
     }
 
@@ -50,40 +323,61 @@ impl<'a> Event<'a> {
         unsafe { std::slice::from_raw_parts(ptr, std::mem::size_of::<seq_ioctl::Event>()) }
     }
 
-    #[allow(unused_unsafe)]
-    pub unsafe fn read(buff: &'a [u8]) -> Event<'a> {
-        assert!(std::mem::size_of::<seq_ioctl::Event>() <= buff.len());
-        let raw: &seq_ioctl::Event = unsafe { &*(buff.as_ptr() as *const _) };
+    /// Append the event as the sequencer reads and writes it
+    ///
+    /// Variable length events are followed by their payload, `EvExt::len`
+    /// tells the kernel how many bytes of it belong to the event.
+    pub fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self.event_bytes());
+        if !self.raw.flags.is_lenght_fixed() {
+            out.extend_from_slice(&self.raw_extra);
+        }
+    }
+
+    /// Size of `encode`d event
+    pub fn encoded_len(&self) -> usize {
+        let extra = if self.raw.flags.is_lenght_fixed() {
+            0
+        } else {
+            self.raw_extra.len()
+        };
+        std::mem::size_of::<seq_ioctl::Event>() + extra
+    }
 
-        let kind = EventKind::from(raw.type_);
+    /// Parse the event at the start of `buff`, as the sequencer reads it
+    ///
+    /// The header is copied out, so `buff` needs no alignment. Fails with
+    /// `InvalidData` if `buff` ends before the header or before the payload
+    /// announced by `EvExt::len`.
+    pub fn read(buff: &'a [u8]) -> io::Result<Event<'a>> {
+        Self::read_with_len(buff).map(|(event, _)| event)
+    }
 
-        let ext_len = match kind {
-            EventKind::Sysex
-            | EventKind::Bounce
-            | EventKind::UsrVar0
-            | EventKind::UsrVar1
-            | EventKind::UsrVar2
-            | EventKind::UsrVar3
-            | EventKind::UsrVar4 => {
-                debug_assert!(raw.flags.is_lenght_fixed());
+    /// `read`, and how many bytes of `buff` the event takes up
+    pub(crate) fn read_with_len(buff: &'a [u8]) -> io::Result<(Event<'a>, usize)> {
+        let header = std::mem::size_of::<seq_ioctl::Event>();
+        if buff.len() < header {
+            return Err(truncated("sequencer event"));
+        }
+        // every bit pattern is a valid `seq_ioctl::Event`, only the
+        // alignment of `buff` is unknown
+        let raw: seq_ioctl::Event = unsafe { std::ptr::read_unaligned(buff.as_ptr() as *const _) };
 
-                let ext = unsafe { &raw.data.ext };
-                ext.len as usize
-            }
+        let ext_len = match raw.data() {
+            seq_ioctl::EventDataRef::Ext(ext) => ext.len as usize,
             _ => 0,
         };
+        let raw_extra = buff[header..]
+            .get(..ext_len)
+            .ok_or_else(|| truncated("sequencer event"))?;
 
-        let offset = std::mem::size_of::<seq_ioctl::Event>();
-        let raw_extra = &buff[offset..];
-        let raw_extra = &raw_extra[..ext_len];
-
-        assert_eq!(ext_len, raw_extra.len());
-
-        Self {
-            kind,
-            raw: Cow::Borrowed(raw),
+        let event = Self {
+            kind: EventKind::from(raw.type_),
+            raw: Cow::Owned(raw),
             raw_extra: Cow::Borrowed(raw_extra),
-        }
+        };
+
+        Ok((event, header + ext_len))
     }
 
     pub fn kind(&self) -> &EventKind {
@@ -99,12 +393,11 @@ impl<'a> Event<'a> {
     }
 
     pub fn time(&self) -> EventTime {
-        if self.raw.flags.is_time_tick() {
-            let tick = unsafe { self.raw.time.tick };
-            EventTime::Tick(tick)
-        } else {
-            let time = unsafe { &self.raw.time.time };
-            EventTime::Time(Duration::new(time.tv_sec as u64, time.tv_nsec))
+        match self.raw.time() {
+            seq_ioctl::EventTime::Tick(tick) => EventTime::Tick(tick),
+            seq_ioctl::EventTime::Real(time) => {
+                EventTime::Time(Duration::new(time.tv_sec as u64, time.tv_nsec))
+            }
         }
     }
 
@@ -121,294 +414,213 @@ impl<'a> Event<'a> {
     }
 
     pub fn data(&self) -> EventData<'_> {
-        match self.kind {
-            // system messages
-            // event data type = snd_seq_result
-            EventKind::System | EventKind::Result => {
-                let result = unsafe { &self.raw.data.result };
-                EventData::Result(result)
-            }
-
-            // note messages (channel specific)
-            // event data type = snd_seq_ev_note
-            EventKind::Note | EventKind::Noteon | EventKind::Noteoff | EventKind::Keypress => {
-                let note = unsafe { &self.raw.data.note };
-                EventData::Note(note)
-            }
-
-            // control messages (channel specific)
-            // event data type = snd_seq_ev_ctrl
-            // synchronisation messages
-            // event data type = snd_seq_ev_ctrl
-            EventKind::Controller
-            | EventKind::Pgmchange
-            | EventKind::Chanpress
-            | EventKind::Pitchbend
-            | EventKind::Control14
-            | EventKind::Nonregparam
-            | EventKind::Regparam
-            | EventKind::Songpos
-            | EventKind::Songsel
-            | EventKind::Qframe
-            | EventKind::Timesign
-            | EventKind::Keysign => {
-                let control = unsafe { &self.raw.data.control };
-                EventData::Control(control)
-            }
-
-            // timer messages
-            // event data type = snd_seq_ev_queue_control
-            EventKind::SyncPos | EventKind::Tick | EventKind::SetposTick => {
-                let queue = unsafe { &self.raw.data.queue };
-                let position = unsafe { queue.param.position };
-                EventData::QueueControl {
-                    queue: &queue.queue,
-                    data: QueueControlEventData::Position(position),
-                }
-            }
-
-            EventKind::SetposTime => {
-                let queue = unsafe { &self.raw.data.queue };
-
-                let value = unsafe {
-                    Duration::new(
-                        queue.param.time.time.tv_sec as u64,
-                        queue.param.time.time.tv_nsec,
-                    )
-                };
-
-                EventData::QueueControl {
-                    queue: &queue.queue,
-                    data: QueueControlEventData::Time(value),
-                }
-            }
-
-            EventKind::Tempo => {
-                let queue = unsafe { &self.raw.data.queue };
-                let value = unsafe { queue.param.value };
-
-                EventData::QueueControl {
-                    queue: &queue.queue,
-                    data: QueueControlEventData::Value(value),
-                }
-            }
-
-            EventKind::QueueSkew => {
-                let queue = unsafe { &self.raw.data.queue };
-                let skew = unsafe { queue.param.skew };
-
-                EventData::QueueControl {
-                    queue: &queue.queue,
-                    data: QueueControlEventData::Skew(skew),
-                }
-            }
-
-            EventKind::Start | EventKind::Continue | EventKind::Stop | EventKind::Clock => {
-                let queue = unsafe { &self.raw.data.queue };
-                EventData::QueueControl {
-                    queue: &queue.queue,
-                    data: QueueControlEventData::None,
-                }
-            }
-
-            // others
-            // event data type = none
-            EventKind::TuneRequest | EventKind::Reset | EventKind::Sensing | EventKind::None => {
-                EventData::None
-            }
-
-            // system status messages (broadcast for subscribers)
-            // event data type = snd_seq_addr
-            EventKind::ClientStart
-            | EventKind::ClientExit
-            | EventKind::ClientChange
-            | EventKind::PortStart
-            | EventKind::PortExit
-            | EventKind::PortChange => {
-                let addr = unsafe { &self.raw.data.addr };
-                EventData::Addr(addr)
-            }
-
-            // port connection changes
-            // event data type = snd_seq_connect
-            EventKind::PortSubscribed | EventKind::PortUnsubscribed => {
-                let connect = unsafe { &self.raw.data.connect };
-                EventData::Connect(connect)
-            }
-
-            // echo back, kernel private messages
-            // event data type = any
-            // user-defined events with fixed length
-            // event data type = any
-            EventKind::Echo
-            | EventKind::Oss
-            | EventKind::Usr0
-            | EventKind::Usr1
-            | EventKind::Usr2
-            | EventKind::Usr3
-            | EventKind::Usr4
-            | EventKind::Usr5
-            | EventKind::Usr6
-            | EventKind::Usr7
-            | EventKind::Usr8
-            | EventKind::Usr9 => {
-                let data = unsafe { &self.raw.data.raw8.d };
-                EventData::Raw8(data)
-            }
-
-            // variable length events
-            // event data type = snd_seq_ev_ext
-            // (SNDRV_SEQ_EVENT_LENGTH_VARIABLE must be set)
-            EventKind::Sysex
-            | EventKind::Bounce
-            | EventKind::UsrVar0
-            | EventKind::UsrVar1
-            | EventKind::UsrVar2
-            | EventKind::UsrVar3
-            | EventKind::UsrVar4 => EventData::Ext(&self.raw_extra),
+        use seq_ioctl::{EventDataRef, QueueControlParam};
+
+        match self.raw.data() {
+            EventDataRef::Result(result) => EventData::Result(result),
+            EventDataRef::Note(note) => EventData::Note(note),
+            EventDataRef::Control(control) => EventData::Control(control),
+            EventDataRef::Queue { control, param } => EventData::QueueControl {
+                queue: &control.queue,
+                data: match param {
+                    QueueControlParam::None => QueueControlEventData::None(self.queue_param()),
+                    QueueControlParam::Value(value) => QueueControlEventData::Value(value),
+                    QueueControlParam::Tick(position) | QueueControlParam::Position(position) => {
+                        QueueControlEventData::Position(position)
+                    }
+                    QueueControlParam::Time(time) => {
+                        QueueControlEventData::Time(Duration::new(time.tv_sec as u64, time.tv_nsec))
+                    }
+                    QueueControlParam::Skew(skew) => QueueControlEventData::Skew(skew),
+                },
+            },
+            EventDataRef::Addr(addr) => EventData::Addr(addr),
+            EventDataRef::Connect(connect) => EventData::Connect(connect),
+            EventDataRef::Raw8(raw8) => EventData::Raw8(&raw8.d),
+            EventDataRef::Ext(_) => EventData::Ext(&self.raw_extra),
+            EventDataRef::Quote(quote) => EventData::Quote(QuotedEvent::from(*quote)),
+            EventDataRef::None => EventData::None(self.raw_data()),
         }
     }
 
-    pub fn event_with_data(&self) -> EventWithData {
-        match self.kind {
+    pub fn event_with_data(&self) -> EventWithData<'_> {
+        use EventData as D;
+        use QueueControlEventData as Q;
+
+        match (self.kind, self.data()) {
             // system messages
             // event data type = snd_seq_result
-            EventKind::System => EventWithData::System(unsafe { self.raw.data.result }),
-            EventKind::Result => EventWithData::Result(unsafe { self.raw.data.result }),
+            (EventKind::System, D::Result(result)) => EventWithData::System(*result),
+            (EventKind::Result, D::Result(result)) => EventWithData::Result(*result),
 
             // note messages (channel specific)
             // event data type = snd_seq_ev_note
-            EventKind::Note => EventWithData::Note(unsafe { self.raw.data.note }),
-            EventKind::Noteon => EventWithData::NoteOn(unsafe { self.raw.data.note }),
-            EventKind::Noteoff => EventWithData::NoteOff(unsafe { self.raw.data.note }),
-            EventKind::Keypress => EventWithData::KeyPress(unsafe { self.raw.data.note }),
+            (EventKind::Note, D::Note(note)) => EventWithData::Note(*note),
+            (EventKind::Noteon, D::Note(note)) => EventWithData::NoteOn(*note),
+            (EventKind::Noteoff, D::Note(note)) => EventWithData::NoteOff(*note),
+            (EventKind::Keypress, D::Note(note)) => EventWithData::KeyPress(*note),
 
             // control messages (channel specific)
             // event data type = snd_seq_ev_ctrl
             // synchronisation messages
             // event data type = snd_seq_ev_ctrl
-            EventKind::Controller => EventWithData::Controller(unsafe { self.raw.data.control }),
-            EventKind::Pgmchange => EventWithData::Pgmchange(unsafe { self.raw.data.control }),
-            EventKind::Chanpress => EventWithData::Chanpress(unsafe { self.raw.data.control }),
-            EventKind::Pitchbend => EventWithData::Pitchbend(unsafe { self.raw.data.control }),
-            EventKind::Control14 => EventWithData::Control14(unsafe { self.raw.data.control }),
-            EventKind::Nonregparam => EventWithData::Nonregparam(unsafe { self.raw.data.control }),
-            EventKind::Regparam => EventWithData::Regparam(unsafe { self.raw.data.control }),
-            EventKind::Songpos => EventWithData::Songpos(unsafe { self.raw.data.control }),
-            EventKind::Songsel => EventWithData::Songsel(unsafe { self.raw.data.control }),
-            EventKind::Qframe => EventWithData::Qframe(unsafe { self.raw.data.control }),
-            EventKind::Timesign => EventWithData::Timesign(unsafe { self.raw.data.control }),
-            EventKind::Keysign => EventWithData::Keysign(unsafe { self.raw.data.control }),
+            (EventKind::Controller, D::Control(ctrl)) => EventWithData::Controller(*ctrl),
+            (EventKind::Pgmchange, D::Control(ctrl)) => EventWithData::Pgmchange(*ctrl),
+            (EventKind::Chanpress, D::Control(ctrl)) => EventWithData::Chanpress(*ctrl),
+            (EventKind::Pitchbend, D::Control(ctrl)) => EventWithData::Pitchbend(*ctrl),
+            (EventKind::Control14, D::Control(ctrl)) => EventWithData::Control14(*ctrl),
+            (EventKind::Nonregparam, D::Control(ctrl)) => EventWithData::Nonregparam(*ctrl),
+            (EventKind::Regparam, D::Control(ctrl)) => EventWithData::Regparam(*ctrl),
+            (EventKind::Songpos, D::Control(ctrl)) => EventWithData::Songpos(*ctrl),
+            (EventKind::Songsel, D::Control(ctrl)) => EventWithData::Songsel(*ctrl),
+            (EventKind::Qframe, D::Control(ctrl)) => EventWithData::Qframe(*ctrl),
+            (EventKind::Timesign, D::Control(ctrl)) => EventWithData::Timesign(*ctrl),
+            (EventKind::Keysign, D::Control(ctrl)) => EventWithData::Keysign(*ctrl),
 
             // timer messages
             // event data type = snd_seq_ev_queue_control
-            EventKind::SyncPos => EventWithData::SyncPos {
-                queue: unsafe { self.raw.data.queue.queue },
-                position: unsafe { self.raw.data.queue.param.position },
+            (EventKind::SyncPos, D::QueueControl { queue, data }) => EventWithData::SyncPos {
+                queue: *queue,
+                position: data.position(),
             },
-            EventKind::Tick => EventWithData::Tick {
-                queue: unsafe { self.raw.data.queue.queue },
-                position: unsafe { self.raw.data.queue.param.position },
+            (EventKind::Tick, D::QueueControl { queue, data }) => EventWithData::Tick {
+                queue: *queue,
+                position: data.position(),
             },
-            EventKind::SetposTick => EventWithData::SetposTick {
-                queue: unsafe { self.raw.data.queue.queue },
-                position: unsafe { self.raw.data.queue.param.position },
+            (EventKind::SetposTick, D::QueueControl { queue, data }) => EventWithData::SetposTick {
+                queue: *queue,
+                position: data.position(),
+            },
+            (
+                EventKind::SetposTime,
+                D::QueueControl {
+                    queue,
+                    data: Q::Time(position),
+                },
+            ) => EventWithData::SetposTime {
+                queue: *queue,
+                position,
+            },
+            (
+                EventKind::Tempo,
+                D::QueueControl {
+                    queue,
+                    data: Q::Value(value),
+                },
+            ) => EventWithData::Tempo {
+                queue: *queue,
+                value,
+            },
+            (
+                EventKind::QueueSkew,
+                D::QueueControl {
+                    queue,
+                    data: Q::Skew(skew),
+                },
+            ) => EventWithData::QueueSkew {
+                queue: *queue,
+                skew,
+            },
+            (EventKind::Start, D::QueueControl { queue, .. }) => EventWithData::Start {
+                queue: *queue,
+                param: self.queue_param(),
+            },
+            (EventKind::Continue, D::QueueControl { queue, .. }) => EventWithData::Continue {
+                queue: *queue,
+                param: self.queue_param(),
+            },
+            (EventKind::Stop, D::QueueControl { queue, .. }) => EventWithData::Stop {
+                queue: *queue,
+                param: self.queue_param(),
+            },
+            (EventKind::Clock, D::QueueControl { queue, .. }) => EventWithData::Clock {
+                queue: *queue,
+                param: self.queue_param(),
             },
-
-            EventKind::SetposTime => {
-                let queue = unsafe { &self.raw.data.queue };
-                let value = unsafe {
-                    Duration::new(
-                        queue.param.time.time.tv_sec as u64,
-                        queue.param.time.time.tv_nsec,
-                    )
-                };
-
-                EventWithData::SetposTime {
-                    queue: queue.queue,
-                    position: value,
-                }
-            }
-
-            EventKind::Tempo => {
-                let queue = unsafe { &self.raw.data.queue };
-                let value = unsafe { queue.param.value };
-
-                EventWithData::Tempo {
-                    queue: queue.queue,
-                    value,
-                }
-            }
-
-            EventKind::QueueSkew => {
-                let queue = unsafe { &self.raw.data.queue };
-                let skew = unsafe { queue.param.skew };
-
-                EventWithData::QueueSkew {
-                    queue: queue.queue,
-                    skew,
-                }
-            }
-
-            EventKind::Start => EventWithData::Start(unsafe { self.raw.data.queue.queue }),
-            EventKind::Continue => EventWithData::Continue(unsafe { self.raw.data.queue.queue }),
-            EventKind::Stop => EventWithData::Stop(unsafe { self.raw.data.queue.queue }),
-            EventKind::Clock => EventWithData::Clock(unsafe { self.raw.data.queue.queue }),
 
             // others
             // event data type = none
-            EventKind::TuneRequest => EventWithData::TuneRequest,
-            EventKind::Reset => EventWithData::Reset,
-            EventKind::Sensing => EventWithData::Sensing,
-            EventKind::None => EventWithData::None,
+            (EventKind::TuneRequest, D::None(d)) => EventWithData::TuneRequest(*d),
+            (EventKind::Reset, D::None(d)) => EventWithData::Reset(*d),
+            (EventKind::Sensing, D::None(d)) => EventWithData::Sensing(*d),
 
             // system status messages (broadcast for subscribers)
             // event data type = snd_seq_addr
-            EventKind::ClientStart => EventWithData::ClientStart(unsafe { self.raw.data.addr }),
-            EventKind::ClientExit => EventWithData::ClientExit(unsafe { self.raw.data.addr }),
-            EventKind::ClientChange => EventWithData::ClientChange(unsafe { self.raw.data.addr }),
-            EventKind::PortStart => EventWithData::PortStart(unsafe { self.raw.data.addr }),
-            EventKind::PortExit => EventWithData::PortExit(unsafe { self.raw.data.addr }),
-            EventKind::PortChange => EventWithData::PortChange(unsafe { self.raw.data.addr }),
+            (EventKind::ClientStart, D::Addr(addr)) => EventWithData::ClientStart(*addr),
+            (EventKind::ClientExit, D::Addr(addr)) => EventWithData::ClientExit(*addr),
+            (EventKind::ClientChange, D::Addr(addr)) => EventWithData::ClientChange(*addr),
+            (EventKind::PortStart, D::Addr(addr)) => EventWithData::PortStart(*addr),
+            (EventKind::PortExit, D::Addr(addr)) => EventWithData::PortExit(*addr),
+            (EventKind::PortChange, D::Addr(addr)) => EventWithData::PortChange(*addr),
 
             // port connection changes
             // event data type = snd_seq_connect
-            EventKind::PortSubscribed => {
-                EventWithData::PortSubscribed(unsafe { self.raw.data.connect })
+            (EventKind::PortSubscribed, D::Connect(connect)) => {
+                EventWithData::PortSubscribed(*connect)
             }
-            EventKind::PortUnsubscribed => {
-                EventWithData::PortUnsubscribed(unsafe { self.raw.data.connect })
+            (EventKind::PortUnsubscribed, D::Connect(connect)) => {
+                EventWithData::PortUnsubscribed(*connect)
             }
 
             // echo back, kernel private messages
             // event data type = any
             // user-defined events with fixed length
             // event data type = any
-            EventKind::Echo => EventWithData::Echo(unsafe { self.raw.data.raw8.d }),
-            EventKind::Oss => EventWithData::Oss(unsafe { self.raw.data.raw8.d }),
-            EventKind::Usr0 => EventWithData::Usr0(unsafe { self.raw.data.raw8.d }),
-            EventKind::Usr1 => EventWithData::Usr1(unsafe { self.raw.data.raw8.d }),
-            EventKind::Usr2 => EventWithData::Usr2(unsafe { self.raw.data.raw8.d }),
-            EventKind::Usr3 => EventWithData::Usr3(unsafe { self.raw.data.raw8.d }),
-            EventKind::Usr4 => EventWithData::Usr4(unsafe { self.raw.data.raw8.d }),
-            EventKind::Usr5 => EventWithData::Usr5(unsafe { self.raw.data.raw8.d }),
-            EventKind::Usr6 => EventWithData::Usr6(unsafe { self.raw.data.raw8.d }),
-            EventKind::Usr7 => EventWithData::Usr7(unsafe { self.raw.data.raw8.d }),
-            EventKind::Usr8 => EventWithData::Usr8(unsafe { self.raw.data.raw8.d }),
-            EventKind::Usr9 => EventWithData::Usr9(unsafe { self.raw.data.raw8.d }),
+            (EventKind::Echo, D::Raw8(d)) => EventWithData::Echo(*d),
+            (EventKind::Oss, D::Raw8(d)) => EventWithData::Oss(*d),
+            (EventKind::Usr0, D::Raw8(d)) => EventWithData::Usr0(*d),
+            (EventKind::Usr1, D::Raw8(d)) => EventWithData::Usr1(*d),
+            (EventKind::Usr2, D::Raw8(d)) => EventWithData::Usr2(*d),
+            (EventKind::Usr3, D::Raw8(d)) => EventWithData::Usr3(*d),
+            (EventKind::Usr4, D::Raw8(d)) => EventWithData::Usr4(*d),
+            (EventKind::Usr5, D::Raw8(d)) => EventWithData::Usr5(*d),
+            (EventKind::Usr6, D::Raw8(d)) => EventWithData::Usr6(*d),
+            (EventKind::Usr7, D::Raw8(d)) => EventWithData::Usr7(*d),
+            (EventKind::Usr8, D::Raw8(d)) => EventWithData::Usr8(*d),
+            (EventKind::Usr9, D::Raw8(d)) => EventWithData::Usr9(*d),
 
             // variable length events
             // event data type = snd_seq_ev_ext
             // (SNDRV_SEQ_EVENT_LENGTH_VARIABLE must be set)
-            EventKind::Sysex => EventWithData::Sysex(&self.raw_extra),
-            EventKind::Bounce => EventWithData::Bounce(&self.raw_extra),
-            EventKind::UsrVar0 => EventWithData::UsrVar0(&self.raw_extra),
-            EventKind::UsrVar1 => EventWithData::UsrVar1(&self.raw_extra),
-            EventKind::UsrVar2 => EventWithData::UsrVar2(&self.raw_extra),
-            EventKind::UsrVar3 => EventWithData::UsrVar3(&self.raw_extra),
-            EventKind::UsrVar4 => EventWithData::UsrVar4(&self.raw_extra),
+            (EventKind::Sysex, D::Ext(ext)) => EventWithData::Sysex(ext),
+            (EventKind::Bounce, D::Ext(ext)) => EventWithData::Bounce(ext),
+            (EventKind::UsrVar0, D::Ext(ext)) => EventWithData::UsrVar0(ext),
+            (EventKind::UsrVar1, D::Ext(ext)) => EventWithData::UsrVar1(ext),
+            (EventKind::UsrVar2, D::Ext(ext)) => EventWithData::UsrVar2(ext),
+            (EventKind::UsrVar3, D::Ext(ext)) => EventWithData::UsrVar3(ext),
+            (EventKind::UsrVar4, D::Ext(ext)) => EventWithData::UsrVar4(ext),
+
+            // kernel events with quote
+            // event data type = snd_seq_ev_quote
+            (EventKind::KernelError, D::Quote(quote)) => EventWithData::KernelError(quote),
+            (EventKind::KernelQuote, D::Quote(quote)) => EventWithData::KernelQuote(quote),
+
+            (EventKind::None, D::None(d)) if self.raw.type_ == seq_ioctl::EventType::NONE => {
+                EventWithData::None(*d)
+            }
+
+            // unknown or mismatched with the event flags
+            (_, D::Ext(bytes)) => EventWithData::Unknown {
+                type_: self.raw.type_.0,
+                data: RawEventData::Variable(bytes),
+            },
+            _ => EventWithData::Unknown {
+                type_: self.raw.type_.0,
+                data: RawEventData::Fixed(*self.raw_data()),
+            },
         }
     }
+
+    /// All bytes of the fixed size data, whatever its type
+    fn raw_data(&self) -> &[u8; 12] {
+        // every field of the union is plain bytes
+        unsafe { &self.raw.data.raw8.d }
+    }
+
+    /// Queue control param as sent, for the types not using it
+    fn queue_param(&self) -> [u32; 2] {
+        unsafe { self.raw.data.queue.param.d32 }
+    }
 }
 
 impl<'a> std::fmt::Debug for Event<'a> {
@@ -425,6 +637,37 @@ impl<'a> std::fmt::Debug for Event<'a> {
     }
 }
 
+/// Direct event with the kind and data of `data`
+impl<'a> From<EventWithData<'a>> for Event<'a> {
+    fn from(data: EventWithData<'a>) -> Self {
+        Event::new(data.kind()).with_event_data(data)
+    }
+}
+
+/// Direct event of `kind` with `data`, which has to match the kind
+///
+/// Types without an `EventKind` can only be rebuilt from `EventWithData::Unknown`.
+impl<'a> From<(EventKind, EventData<'a>)> for Event<'a> {
+    fn from((kind, data): (EventKind, EventData<'a>)) -> Self {
+        let event = Event::new(kind);
+
+        match data {
+            EventData::Result(result) => event.with_data(*result),
+            EventData::Note(note) => event.with_data(*note),
+            EventData::Control(ctrl) => event.with_data(*ctrl),
+            EventData::QueueControl { queue, data } => {
+                event.with_data(EvQueueControl::new(*queue, data.into()))
+            }
+            EventData::Addr(addr) => event.with_data(*addr),
+            EventData::Connect(connect) => event.with_data(*connect),
+            EventData::Raw8(d) => event.with_data(seq_ioctl::EvRaw8 { d: *d }),
+            EventData::Ext(bytes) => event.with_ext(bytes),
+            EventData::Quote(quote) => event.with_data(quote),
+            EventData::None(d) => event.with_data(seq_ioctl::EvRaw8 { d: *d }),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum QueueControlEventData {
     /// Affected value (e.g. tempo)
@@ -434,7 +677,38 @@ pub enum QueueControlEventData {
     Time(Duration),
     /// Queue skew values
     Skew(seq_ioctl::QueueSkew),
-    None,
+    /// param not used by the event type, as sent
+    None([u32; 2]),
+}
+
+impl QueueControlEventData {
+    /// Tick or sync position, 0 for other params
+    fn position(&self) -> u32 {
+        match self {
+            Self::Position(position) => *position,
+            _ => 0,
+        }
+    }
+}
+
+impl From<QueueControlEventData> for seq_ioctl::EvQueueControlParam {
+    fn from(data: QueueControlEventData) -> Self {
+        let mut param = Self::default();
+        match data {
+            QueueControlEventData::Value(value) => param.value = value,
+            // shares its bytes with `time.tick`
+            QueueControlEventData::Position(position) => param.position = position,
+            QueueControlEventData::Time(time) => {
+                param.time = seq_ioctl::Timestamp::real(seq_ioctl::RealTime::new(
+                    time.as_secs() as u32,
+                    time.subsec_nanos(),
+                ))
+            }
+            QueueControlEventData::Skew(skew) => param.skew = skew,
+            QueueControlEventData::None(d32) => param.d32 = d32,
+        }
+        param
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -456,12 +730,71 @@ pub enum EventData<'a> {
     Connect(&'a Connect),
     Raw8(&'a [u8; 12]),
     Ext(&'a [u8]),
-    None,
+    Quote(QuotedEvent),
+    /// data of a type without data, as sent
+    None(&'a [u8; 12]),
+}
+
+/// Error or quote about another event, see `EventWithData::KernelError`
+///
+/// The kernel only passes a pointer to the quoted event in its own memory, so
+/// the event itself can't be recovered, only where it went and why it failed.
+/// The port of the failed event is the destination port of the error.
+/// Delivery errors are only sent after `Seq::set_bounce_errors`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct QuotedEvent {
+    /// destination the quoted event was sent to
+    pub origin: Addr,
+    /// errno of a `KernelError`
+    pub value: u16,
+}
+
+impl From<seq_ioctl::EvQuote> for QuotedEvent {
+    fn from(quote: seq_ioctl::EvQuote) -> Self {
+        Self {
+            origin: quote.origin,
+            value: quote.value,
+        }
+    }
+}
+
+impl From<QuotedEvent> for seq_ioctl::EvQuote {
+    fn from(quote: QuotedEvent) -> Self {
+        Self {
+            origin: quote.origin,
+            value: quote.value,
+            event: std::ptr::null_mut(),
+        }
+    }
+}
+
+impl From<QuotedEvent> for seq_ioctl::EventData {
+    fn from(quote: QuotedEvent) -> Self {
+        seq_ioctl::EvQuote::from(quote).into()
+    }
+}
+
+impl QuotedEvent {
+    /// Error of a `KernelError`, `None` if `value` is not an errno
+    pub fn error(&self) -> Option<rustix::io::Errno> {
+        errno(self.value as u32)
+    }
+}
+
+/// Event that could not be delivered, decoded from a `Bounce` event
+///
+/// Unlike `QuotedEvent` a bounce carries a copy of the whole event.
+#[derive(Debug, Clone)]
+pub struct BouncedEvent<'a> {
+    pub error: rustix::io::Errno,
+    pub original: Event<'a>,
 }
 
 /// sequencer event type
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, num_derive::FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EventKind {
     /// system status; event data type = #snd_seq_result_t
     System = seq_ioctl::EventType::SYSTEM.0,
@@ -599,6 +932,11 @@ pub enum EventKind {
     UsrVar4,
 
     /* 150-151: kernel events with quote - DO NOT use in user clients */
+    /// delivery error reported by the kernel; event data type = #snd_seq_ev_quote_t
+    KernelError = seq_ioctl::EventType::KERNEL_ERROR.0,
+    /// quoted event; event data type = #snd_seq_ev_quote_t
+    KernelQuote,
+
     /// NOP; ignored in any case"]
     None = seq_ioctl::EventType::NONE.0,
 }
@@ -622,7 +960,13 @@ impl From<EventKind> for seq_ioctl::EventType {
 }
 
 /// sequencer event type
+///
+/// With the `serde` feature this serializes externally tagged by variant name,
+/// e.g. `{"NoteOn": {"channel": 0, "note": 60, ...}}`. Variable length
+/// payloads are byte sequences. There is no `Deserialize` because the
+/// payload is borrowed from the read buffer.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum EventWithData<'a> {
     /// system status; event data type = #snd_seq_result_t
     System(EvResult),
@@ -665,11 +1009,13 @@ pub enum EventWithData<'a> {
     Keysign(EvCtrl),
 
     /// MIDI Real Time Start message; event data type = #snd_seq_ev_queue_control_t
-    Start(QueueId),
+    ///
+    /// `param` is not used by the kernel, it is kept as sent.
+    Start { queue: QueueId, param: [u32; 2] },
     /// MIDI Real Time Continue message; event data type = #snd_seq_ev_queue_control_t
-    Continue(QueueId),
+    Continue { queue: QueueId, param: [u32; 2] },
     /// MIDI Real Time Stop message; event data type = #snd_seq_ev_queue_control_t
-    Stop(QueueId),
+    Stop { queue: QueueId, param: [u32; 2] },
     /// Set tick queue position; event data type = #snd_seq_ev_queue_control_t
     SetposTick { queue: QueueId, position: u32 },
     /// Set real-time queue position; event data type = #snd_seq_ev_queue_control_t
@@ -677,7 +1023,7 @@ pub enum EventWithData<'a> {
     /// (SMF) Tempo event; event data type = #snd_seq_ev_queue_control_t
     Tempo { queue: QueueId, value: i32 },
     /// MIDI Real Time Clock message; event data type = #snd_seq_ev_queue_control_t
-    Clock(QueueId),
+    Clock { queue: QueueId, param: [u32; 2] },
     /// MIDI Real Time Tick message; event data type = #snd_seq_ev_queue_control_t
     Tick { queue: QueueId, position: u32 },
     /// Queue timer skew; event data type = #snd_seq_ev_queue_control_t
@@ -685,12 +1031,12 @@ pub enum EventWithData<'a> {
     /// Sync position changed; event data type = #snd_seq_ev_queue_control_t
     SyncPos { queue: QueueId, position: u32 },
 
-    /// Tune request; event data type = none
-    TuneRequest,
+    /// Tune request; event data type = none, the unused bytes are kept as sent
+    TuneRequest([u8; 12]),
     /// Reset to power-on state; event data type = none
-    Reset,
+    Reset([u8; 12]),
     /// Active sensing event; event data type = none
-    Sensing,
+    Sensing([u8; 12]),
 
     /// Echo-back event; event data type = any type
     Echo([u8; 12]),
@@ -760,6 +1106,105 @@ pub enum EventWithData<'a> {
     UsrVar4(&'a [u8]),
 
     /* 150-151: kernel events with quote - DO NOT use in user clients */
-    /// NOP; ignored in any case"]
-    None,
+    /// delivery error reported by the kernel; event data type = #snd_seq_ev_quote_t
+    KernelError(QuotedEvent),
+    /// quoted event; event data type = #snd_seq_ev_quote_t
+    KernelQuote(QuotedEvent),
+
+    /// NOP; ignored in any case
+    None([u8; 12]),
+
+    /// Type this crate does not know, or data not matching the type
+    Unknown { type_: u8, data: RawEventData<'a> },
+}
+
+/// Data of an `EventWithData::Unknown` event, as sent
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum RawEventData<'a> {
+    Fixed([u8; 12]),
+    /// payload of a variable length event
+    Variable(&'a [u8]),
+}
+
+impl<'a> EventWithData<'a> {
+    pub fn kind(&self) -> EventKind {
+        use EventWithData as E;
+
+        match self {
+            E::System(_) => EventKind::System,
+            E::Result(_) => EventKind::Result,
+            E::Note(_) => EventKind::Note,
+            E::NoteOn(_) => EventKind::Noteon,
+            E::NoteOff(_) => EventKind::Noteoff,
+            E::KeyPress(_) => EventKind::Keypress,
+            E::Controller(_) => EventKind::Controller,
+            E::Pgmchange(_) => EventKind::Pgmchange,
+            E::Chanpress(_) => EventKind::Chanpress,
+            E::Pitchbend(_) => EventKind::Pitchbend,
+            E::Control14(_) => EventKind::Control14,
+            E::Nonregparam(_) => EventKind::Nonregparam,
+            E::Regparam(_) => EventKind::Regparam,
+            E::Songpos(_) => EventKind::Songpos,
+            E::Songsel(_) => EventKind::Songsel,
+            E::Qframe(_) => EventKind::Qframe,
+            E::Timesign(_) => EventKind::Timesign,
+            E::Keysign(_) => EventKind::Keysign,
+            E::Start { .. } => EventKind::Start,
+            E::Continue { .. } => EventKind::Continue,
+            E::Stop { .. } => EventKind::Stop,
+            E::SetposTick { .. } => EventKind::SetposTick,
+            E::SetposTime { .. } => EventKind::SetposTime,
+            E::Tempo { .. } => EventKind::Tempo,
+            E::Clock { .. } => EventKind::Clock,
+            E::Tick { .. } => EventKind::Tick,
+            E::QueueSkew { .. } => EventKind::QueueSkew,
+            E::SyncPos { .. } => EventKind::SyncPos,
+            E::TuneRequest(_) => EventKind::TuneRequest,
+            E::Reset(_) => EventKind::Reset,
+            E::Sensing(_) => EventKind::Sensing,
+            E::Echo(_) => EventKind::Echo,
+            E::Oss(_) => EventKind::Oss,
+            E::ClientStart(_) => EventKind::ClientStart,
+            E::ClientExit(_) => EventKind::ClientExit,
+            E::ClientChange(_) => EventKind::ClientChange,
+            E::PortStart(_) => EventKind::PortStart,
+            E::PortExit(_) => EventKind::PortExit,
+            E::PortChange(_) => EventKind::PortChange,
+            E::PortSubscribed(_) => EventKind::PortSubscribed,
+            E::PortUnsubscribed(_) => EventKind::PortUnsubscribed,
+            E::Usr0(_) => EventKind::Usr0,
+            E::Usr1(_) => EventKind::Usr1,
+            E::Usr2(_) => EventKind::Usr2,
+            E::Usr3(_) => EventKind::Usr3,
+            E::Usr4(_) => EventKind::Usr4,
+            E::Usr5(_) => EventKind::Usr5,
+            E::Usr6(_) => EventKind::Usr6,
+            E::Usr7(_) => EventKind::Usr7,
+            E::Usr8(_) => EventKind::Usr8,
+            E::Usr9(_) => EventKind::Usr9,
+            E::Sysex(_) => EventKind::Sysex,
+            E::Bounce(_) => EventKind::Bounce,
+            E::UsrVar0(_) => EventKind::UsrVar0,
+            E::UsrVar1(_) => EventKind::UsrVar1,
+            E::UsrVar2(_) => EventKind::UsrVar2,
+            E::UsrVar3(_) => EventKind::UsrVar3,
+            E::UsrVar4(_) => EventKind::UsrVar4,
+            E::KernelError(_) => EventKind::KernelError,
+            E::KernelQuote(_) => EventKind::KernelQuote,
+            E::None(_) => EventKind::None,
+            E::Unknown { type_, .. } => EventKind::from(seq_ioctl::EventType(*type_)),
+        }
+    }
+}
+
+/// `Errno` panics outside of the kernel's `1..=4095`
+fn errno(raw: u32) -> Option<rustix::io::Errno> {
+    (1..=4095)
+        .contains(&raw)
+        .then(|| rustix::io::Errno::from_raw_os_error(raw as i32))
+}
+
+fn truncated(what: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("truncated {}", what))
 }
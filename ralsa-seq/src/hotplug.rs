@@ -0,0 +1,461 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    io,
+    os::{
+        fd::{AsFd, BorrowedFd, OwnedFd},
+        raw::c_int,
+        unix::prelude::{AsRawFd, RawFd},
+    },
+};
+
+use alsa_ioctl::seq_ioctl::{self, port, Addr, ClientId, PortCapability, PortType};
+use rustix::event::epoll;
+
+use crate::{event::EventWithData, Card, Port, Seq, SeqInput};
+
+/// Device change reported by `Hotplug`
+#[derive(Debug, Clone)]
+pub enum HotplugEvent {
+    /// sound card appeared, with the kernel clients it has so far
+    CardAdded(Card),
+    /// sound card disappeared, with the kernel clients it had
+    CardRemoved(Card),
+    /// sequencer client appeared, `ClientInfo::card` links it to a card
+    ClientAdded(seq_ioctl::ClientInfo),
+    /// sequencer client disappeared
+    ClientRemoved(seq_ioctl::ClientInfo),
+    PortAdded(seq_ioctl::PortInfo),
+    PortRemoved(Addr),
+}
+
+/// One pollable stream of card and sequencer changes
+///
+/// Merges udev `sound` events (with the `udev` feature) and the
+/// `system:announce` port into `HotplugEvent`s, see `HotplugState` for how
+/// both sources are merged.
+#[derive(Debug)]
+pub struct Hotplug {
+    seq: Seq,
+    input: SeqInput,
+    _port: Port,
+    #[cfg(feature = "udev")]
+    udev: Option<UdevMonitor>,
+    epoll: OwnedFd,
+    state: HotplugState,
+}
+
+impl Hotplug {
+    pub fn new() -> io::Result<Self> {
+        let (seq, input, _) = Seq::open()?;
+
+        let announce = seq.create_port(
+            seq_ioctl::PortInfo::default()
+                .with_name("hotplug")
+                .with_capability(PortCapability::WRITE | PortCapability::NO_EXPORT)
+                .with_type(PortType::APPLICATION),
+        )?;
+        announce.connect_src(
+            &seq,
+            Addr::new(ClientId::SYSTEM.0 as u8, port::SYSTEM_ANNOUNCE),
+        )?;
+
+        let epoll = epoll::create(epoll::CreateFlags::CLOEXEC)?;
+        epoll::add(
+            &epoll,
+            &seq,
+            epoll::EventData::new_u64(0),
+            epoll::EventFlags::IN,
+        )?;
+
+        #[cfg(feature = "udev")]
+        let udev = UdevMonitor::new().ok();
+        #[cfg(feature = "udev")]
+        if let Some(udev) = &udev {
+            epoll::add(
+                &epoll,
+                udev,
+                epoll::EventData::new_u64(1),
+                epoll::EventFlags::IN,
+            )?;
+        }
+        #[cfg(feature = "udev")]
+        let has_udev = udev.is_some();
+        #[cfg(not(feature = "udev"))]
+        let has_udev = false;
+
+        let clients: Vec<_> = seq
+            .clients_iter()
+            .filter(|client| client.client != seq.inner.client_id as c_int)
+            .collect();
+        let state = HotplugState::new(has_udev, seq.cards()?, clients);
+
+        Ok(Self {
+            seq,
+            input,
+            _port: announce,
+            #[cfg(feature = "udev")]
+            udev,
+            epoll,
+            state,
+        })
+    }
+
+    /// Cards known right now
+    pub fn cards(&self) -> impl Iterator<Item = &Card> {
+        self.state.cards()
+    }
+
+    /// Next change, `None` when nothing is pending
+    ///
+    /// Never blocks, poll the watcher for `POLLIN` to wait for changes.
+    pub fn next_event(&mut self) -> io::Result<Option<HotplugEvent>> {
+        if !self.state.has_pending() {
+            #[cfg(feature = "udev")]
+            self.read_udev()?;
+            self.read_announce()?;
+        }
+
+        Ok(self.state.next_event())
+    }
+
+    #[cfg(feature = "udev")]
+    fn read_udev(&mut self) -> io::Result<()> {
+        let Some(udev) = &mut self.udev else {
+            return Ok(());
+        };
+
+        let changes: Vec<_> = udev
+            .socket
+            .by_ref()
+            .filter_map(|event| {
+                let index = event
+                    .sysname()
+                    .to_str()?
+                    .strip_prefix("card")?
+                    .parse()
+                    .ok()?;
+                match event.event_type() {
+                    udev::EventType::Remove => Some((index, false)),
+                    _ if event.property_value("SOUND_INITIALIZED").is_some() => Some((index, true)),
+                    _ => None,
+                }
+            })
+            .collect();
+
+        for (index, added) in changes {
+            if !added {
+                self.state.card_removed(index);
+            } else if !self.state.has_card(index) {
+                // udev knows the card even if `Seq::cards` does not list it yet
+                let card = self.find_card(index)?.unwrap_or_else(|| Card::new(index));
+                self.state.card_added(card);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn read_announce(&mut self) -> io::Result<()> {
+        let mut changes = Vec::new();
+
+        while let Some(event) = self.input.input_event(true)? {
+            if event.source().client != ClientId::SYSTEM.0 as u8 {
+                continue;
+            }
+
+            match event.event_with_data() {
+                EventWithData::ClientStart(addr) => changes.push((addr, Change::ClientStart)),
+                EventWithData::ClientExit(addr) => changes.push((addr, Change::ClientExit)),
+                EventWithData::PortStart(addr) => changes.push((addr, Change::PortStart)),
+                EventWithData::PortExit(addr) => changes.push((addr, Change::PortExit)),
+                _ => {}
+            }
+        }
+
+        for (addr, change) in changes {
+            let client = addr.client as c_int;
+            match change {
+                Change::ClientStart if !self.state.has_client(client) => {
+                    let mut info = seq_ioctl::ClientInfo::new(client);
+                    // the client may already be gone again
+                    if seq_ioctl::get_client_info(&self.seq, &mut info).is_err() {
+                        continue;
+                    }
+
+                    let card =
+                        if info.card >= 0 && !self.state.has_card(info.card) && !self.has_udev() {
+                            self.find_card(info.card)?
+                        } else {
+                            None
+                        };
+                    self.state.client_started(info, card);
+                }
+                Change::ClientExit => self.state.client_exited(client),
+                Change::PortStart if self.state.has_client(client) => {
+                    let mut info = seq_ioctl::PortInfo::new(addr);
+                    // the port may already be gone again
+                    if seq_ioctl::get_port_info(&self.seq, &mut info).is_ok() {
+                        self.state.port_started(info);
+                    }
+                }
+                Change::PortExit => self.state.port_exited(addr),
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn has_udev(&self) -> bool {
+        #[cfg(feature = "udev")]
+        return self.udev.is_some();
+        #[cfg(not(feature = "udev"))]
+        return false;
+    }
+
+    fn find_card(&self, index: c_int) -> io::Result<Option<Card>> {
+        Ok(self
+            .seq
+            .cards()?
+            .into_iter()
+            .find(|card| card.index == index))
+    }
+}
+
+enum Change {
+    ClientStart,
+    ClientExit,
+    PortStart,
+    PortExit,
+}
+
+/// Cards and clients known to a `Hotplug`, and the changes not yet read
+///
+/// Both udev and the announce port report the same card, so each change is
+/// only reported once:
+///
+/// - with udev a card is added on `SOUND_INITIALIZED`, which carries its udev
+///   identity, together with the kernel clients that started before it
+/// - without udev a card is added when its first kernel client starts, a
+///   kernel client whose card cannot be looked up is added on its own
+/// - with udev a card is removed on the udev `remove` event, together with
+///   the kernel clients that exited before it; without udev when its last
+///   kernel client exits. Its clients are not reported again
+/// - port changes are only reported for known clients
+///
+/// Cards and clients it is created with are known, but not reported. It does
+/// no I/O, the caller looks cards and clients up.
+#[derive(Debug, Default)]
+pub struct HotplugState {
+    udev: bool,
+    cards: BTreeMap<c_int, Card>,
+    clients: BTreeMap<c_int, seq_ioctl::ClientInfo>,
+    /// kernel clients started before udev reported their card
+    waiting: Vec<seq_ioctl::ClientInfo>,
+    pending: VecDeque<HotplugEvent>,
+}
+
+impl HotplugState {
+    /// `udev` tells whether cards are reported by udev, the clients of
+    /// `cards` are known without being listed in `clients`
+    pub fn new(
+        udev: bool,
+        cards: impl IntoIterator<Item = Card>,
+        clients: impl IntoIterator<Item = seq_ioctl::ClientInfo>,
+    ) -> Self {
+        let cards: BTreeMap<_, _> = cards.into_iter().map(|card| (card.index, card)).collect();
+        let clients = cards
+            .values()
+            .flat_map(|card| card.clients.iter().copied())
+            .chain(clients)
+            .map(|client| (client.client, client))
+            .collect();
+
+        Self {
+            udev,
+            cards,
+            clients,
+            waiting: Vec::new(),
+            pending: VecDeque::new(),
+        }
+    }
+
+    pub fn cards(&self) -> impl Iterator<Item = &Card> {
+        self.cards.values()
+    }
+
+    pub fn has_card(&self, index: c_int) -> bool {
+        self.cards.contains_key(&index)
+    }
+
+    pub fn has_client(&self, client: c_int) -> bool {
+        self.clients.contains_key(&client)
+    }
+
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Oldest change not read yet
+    pub fn next_event(&mut self) -> Option<HotplugEvent> {
+        self.pending.pop_front()
+    }
+
+    /// Card seen by udev, or through its first client without udev
+    pub fn card_added(&mut self, mut card: Card) {
+        if self.cards.contains_key(&card.index) {
+            return;
+        }
+
+        let (waiting, others) = std::mem::take(&mut self.waiting)
+            .into_iter()
+            .partition(|client| client.card == card.index);
+        self.waiting = others;
+        for client in waiting {
+            if !card
+                .clients
+                .iter()
+                .any(|other| other.client == client.client)
+            {
+                card.clients.push(client);
+            }
+        }
+
+        for client in &card.clients {
+            self.clients.insert(client.client, *client);
+        }
+        self.cards.insert(card.index, card.clone());
+        self.pending.push_back(HotplugEvent::CardAdded(card));
+    }
+
+    pub fn card_removed(&mut self, index: c_int) {
+        self.waiting.retain(|client| client.card != index);
+        let Some(card) = self.cards.remove(&index) else {
+            return;
+        };
+
+        self.clients.retain(|_, client| client.card != index);
+        self.pending.push_back(HotplugEvent::CardRemoved(card));
+    }
+
+    /// Client from the announce port, `card` is its card if it is a new one
+    ///
+    /// `card` is only used without udev.
+    pub fn client_started(&mut self, info: seq_ioctl::ClientInfo, card: Option<Card>) {
+        let waiting = self.waiting.iter().any(|other| other.client == info.client);
+        if waiting || self.clients.contains_key(&info.client) {
+            return;
+        }
+
+        if info.card >= 0 && !self.cards.contains_key(&info.card) {
+            if self.udev {
+                // reported with its card on `SOUND_INITIALIZED`
+                self.waiting.push(info);
+                return;
+            }
+
+            if let Some(card) = card {
+                // first sign of a card, it comes with all its clients
+                self.card_added(card);
+                if self.clients.contains_key(&info.client) {
+                    return;
+                }
+            }
+        }
+
+        self.clients.insert(info.client, info);
+        if let Some(card) = self.cards.get_mut(&info.card) {
+            // an exit kept for the card removal was never reported
+            if let Some(other) = card
+                .clients
+                .iter_mut()
+                .find(|other| other.client == info.client)
+            {
+                *other = info;
+                return;
+            }
+            card.clients.push(info);
+        }
+        self.pending.push_back(HotplugEvent::ClientAdded(info));
+    }
+
+    pub fn client_exited(&mut self, client: c_int) {
+        if let Some(index) = self.waiting.iter().position(|other| other.client == client) {
+            // never reported
+            self.waiting.remove(index);
+            return;
+        }
+        let Some(info) = self.clients.remove(&client) else {
+            return;
+        };
+
+        if let Some(card) = self.cards.get_mut(&info.card) {
+            if self.udev {
+                // reported with the card on the udev `remove` that follows
+                return;
+            }
+            if card.clients.iter().all(|other| other.client == client) {
+                self.card_removed(info.card);
+                return;
+            }
+            card.clients.retain(|other| other.client != client);
+        }
+
+        self.pending.push_back(HotplugEvent::ClientRemoved(info));
+    }
+
+    pub fn port_started(&mut self, info: seq_ioctl::PortInfo) {
+        if self.clients.contains_key(&(info.addr.client as c_int)) {
+            self.pending.push_back(HotplugEvent::PortAdded(info));
+        }
+    }
+
+    pub fn port_exited(&mut self, addr: Addr) {
+        if self.clients.contains_key(&(addr.client as c_int)) {
+            self.pending.push_back(HotplugEvent::PortRemoved(addr));
+        }
+    }
+}
+
+impl AsRawFd for Hotplug {
+    fn as_raw_fd(&self) -> RawFd {
+        self.epoll.as_raw_fd()
+    }
+}
+
+impl AsFd for Hotplug {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.epoll.as_fd()
+    }
+}
+
+/// udev monitor of the `sound` subsystem
+#[cfg(feature = "udev")]
+struct UdevMonitor {
+    socket: udev::MonitorSocket,
+}
+
+#[cfg(feature = "udev")]
+impl UdevMonitor {
+    fn new() -> io::Result<Self> {
+        let socket = udev::MonitorBuilder::new()?
+            .match_subsystem("sound")?
+            .listen()?;
+        Ok(Self { socket })
+    }
+}
+
+#[cfg(feature = "udev")]
+impl std::fmt::Debug for UdevMonitor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UdevMonitor").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "udev")]
+impl AsFd for UdevMonitor {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        // the socket lives as long as `self`
+        unsafe { BorrowedFd::borrow_raw(self.socket.as_raw_fd()) }
+    }
+}
@@ -1,21 +1,44 @@
 use std::{
-    io,
+    fmt, io,
     os::{
         fd::AsFd,
         unix::prelude::{AsRawFd, RawFd},
     },
 };
 
-use rustix::event::PollFlags;
+use rustix::{event::PollFlags, io::Errno};
 
 use super::{event, Seq, CELL_SIZE, SEQ_INPUT_BUF_SIZE};
 
+/// The kernel input pool overflowed and events were dropped
+///
+/// The kernel flushes the pool when reporting it, so reading can go on right
+/// away. It does not say how many events were dropped. Raise the pool with
+/// `Seq::set_input_pool` if it happens often.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overrun;
+
+impl fmt::Display for Overrun {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("sequencer input overrun, events were dropped")
+    }
+}
+
+impl std::error::Error for Overrun {}
+
+impl From<Overrun> for io::Error {
+    fn from(err: Overrun) -> Self {
+        io::Error::new(io::ErrorKind::StorageFull, err)
+    }
+}
+
 #[derive(Debug)]
 pub struct SeqInput {
     seq: Seq,
     input_buffer: Vec<u8>,
     cell_count: usize,
     cell_id: usize,
+    overrun: Option<Overrun>,
 }
 
 impl SeqInput {
@@ -25,6 +48,7 @@ impl SeqInput {
             input_buffer: vec![0u8; SEQ_INPUT_BUF_SIZE * CELL_SIZE],
             cell_count: 0,
             cell_id: 0,
+            overrun: None,
         }
     }
 
@@ -32,20 +56,44 @@ impl SeqInput {
         &self.seq
     }
 
+    /// Fill the input buffer from the sequencer
+    ///
+    /// Fails with an `Overrun` error when the kernel input pool overflowed,
+    /// the next read continues with new events.
     pub fn read(&mut self) -> io::Result<()> {
-        let len = rustix::io::read(&self.seq, &mut self.input_buffer)?;
+        self.cell_count = 0;
+        self.cell_id = 0;
+
+        let len = match rustix::io::read(&self.seq, &mut self.input_buffer) {
+            Ok(len) => len,
+            Err(Errno::NOSPC) => return Err(Overrun.into()),
+            Err(err) => return Err(err.into()),
+        };
 
         self.cell_count = len / CELL_SIZE;
-        self.cell_id = 0;
 
         Ok(())
     }
 
+    /// Overrun hit while `input_event` fetched from the sequencer
+    ///
+    /// Cleared by reading it.
+    pub fn take_overrun(&mut self) -> Option<Overrun> {
+        self.overrun.take()
+    }
+
     pub fn has_input_events(&self) -> bool {
         self.cell_count != 0
     }
 
-    pub fn input_event(&mut self, fetch_sequencer: bool) -> Option<event::Event<'_>> {
+    /// Next event from the input buffer, `None` when it is empty
+    ///
+    /// With `fetch_sequencer` an empty buffer is refilled from the sequencer
+    /// first if it has events, without blocking. An overrun while fetching is
+    /// kept for `take_overrun`, other read errors are returned. Fails with
+    /// `InvalidData` for an event running past the data read, the rest of the
+    /// buffer is dropped.
+    pub fn input_event(&mut self, fetch_sequencer: bool) -> io::Result<Option<event::Event<'_>>> {
         // If there is no events check if fd was read fully
         // Or is there data still left in it
         if !self.has_input_events() && fetch_sequencer {
@@ -55,32 +103,36 @@ impl SeqInput {
             rustix::event::poll(&mut fds, 0).ok();
 
             if fds[0].revents().contains(PollFlags::IN) {
-                self.read().ok();
+                if let Err(err) = self.read() {
+                    if !err.get_ref().is_some_and(|err| err.is::<Overrun>()) {
+                        return Err(err);
+                    }
+                    self.overrun = Some(Overrun);
+                }
             }
         }
 
-        self.has_input_events().then(|| {
-            let offset = self.cell_id * CELL_SIZE;
-            let event = unsafe { event::Event::read(&self.input_buffer[offset..]) };
-
-            self.cell_id += 1;
-            self.cell_count -= 1;
-
-            if let event::EventData::Ext(ext) = event.data() {
-                // TODO: Replace this c'ism with something easier to understand
-                // This is basically snapping to nearest cell size
-                let extra_cells = (ext.len() + CELL_SIZE - 1) / CELL_SIZE;
-
-                if self.cell_count < extra_cells {
-                    // err
-                }
+        if !self.has_input_events() {
+            return Ok(None);
+        }
 
-                self.cell_id += extra_cells;
-                self.cell_count -= extra_cells;
+        let offset = self.cell_id * CELL_SIZE;
+        let end = offset + self.cell_count * CELL_SIZE;
+        let (event, len) = match event::Event::read_with_len(&self.input_buffer[offset..end]) {
+            Ok(read) => read,
+            Err(err) => {
+                // nothing after a broken event can be trusted
+                self.cell_count = 0;
+                return Err(err);
             }
+        };
+
+        // payloads are padded to whole cells, `read` kept `len` within them
+        let cells = len.div_ceil(CELL_SIZE);
+        self.cell_id += cells;
+        self.cell_count -= cells;
 
-            event
-        })
+        Ok(Some(event))
     }
 }
 
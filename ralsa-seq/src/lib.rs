@@ -3,45 +3,56 @@ use std::{
     io,
     os::{
         fd::AsFd,
+        raw::c_int,
         unix::prelude::{AsRawFd, OwnedFd, RawFd},
     },
-    path::{Path, PathBuf},
+    path::Path,
     sync::Arc,
 };
 
-use alsa_ioctl::seq_ioctl::{self, Addr, PortCapability, PortType};
+use alsa_ioctl::seq_ioctl::{self, Addr, Filter, PortCapability, PortType};
 use rustix::fs::{open, Mode, OFlags};
 
-// const SND_SEQ_OBUF_SIZE: usize = 16 * 1024; /* default size */
+const SND_SEQ_OBUF_SIZE: usize = 16 * 1024; /* default size */
 const SEQ_INPUT_BUF_SIZE: usize = 500; /* in event_size aligned */
 
 const MSG_SIZE: usize = std::mem::size_of::<seq_ioctl::Event>();
 const CELL_SIZE: usize = MSG_SIZE;
 
+mod builder;
+pub use builder::{Direction, SeqBuilder};
+
+mod capabilities;
+pub use capabilities::{Capabilities, IncompatibleVersion};
+
+mod card;
+pub use card::Card;
+
+mod client;
+pub use client::{Client, ClientKind};
+
+mod discovery;
+pub use discovery::{find_seq_device, query_sysfs, DiscoveryMethod, SeqDevice};
+
 pub mod event;
 
+mod hotplug;
+pub use hotplug::{Hotplug, HotplugEvent, HotplugState};
+
 mod input;
-pub use input::SeqInput;
+pub use input::{Overrun, SeqInput};
 
 mod output;
-pub use output::SeqOutput;
-
-fn query_seq_path() -> io::Result<PathBuf> {
-    let mut enumerator = udev::Enumerator::new()?;
-    enumerator.match_subsystem("sound")?;
-    enumerator.match_sysname("seq")?;
-
-    enumerator
-        .scan_devices()?
-        .next()
-        .and_then(|dev| dev.devnode().map(ToOwned::to_owned))
-        .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
-}
+pub use output::{OutputBuffer, SeqOutput};
+
+pub mod proc_asound;
 
 #[derive(Debug)]
 struct SeqInner {
     fd: OwnedFd,
     client_id: u32,
+    capabilities: Capabilities,
+    discovery: DiscoveryMethod,
 }
 
 #[derive(Debug, Clone)]
@@ -50,40 +61,128 @@ pub struct Seq {
 }
 
 impl Seq {
+    /// Open the sequencer found by `find_seq_device`
+    ///
+    /// Non-blocking duplex client, see `SeqBuilder` for other options.
     pub fn open() -> io::Result<(Seq, SeqInput, SeqOutput)> {
-        Self::open_path(query_seq_path()?)
+        SeqBuilder::new().open()
     }
 
     pub fn open_path<P>(path: P) -> io::Result<(Seq, SeqInput, SeqOutput)>
     where
         P: AsRef<Path>,
     {
-        let fd = open(
-            path.as_ref(),
-            OFlags::RDWR | OFlags::NONBLOCK,
-            Mode::empty(),
-        )?;
+        SeqBuilder::new().with_path(path).open()
+    }
+
+    pub fn builder() -> SeqBuilder {
+        SeqBuilder::new()
+    }
+
+    fn open_device(
+        path: &Path,
+        discovery: DiscoveryMethod,
+        flags: OFlags,
+    ) -> io::Result<(Seq, SeqInput, SeqOutput)> {
+        let fd = open(path, flags, Mode::empty())?;
 
-        let _version = seq_ioctl::pversion(&fd)?;
+        let capabilities = Capabilities::negotiate(&fd)?;
         let client_id = seq_ioctl::client_id(&fd)?;
 
         let seq = Seq {
             inner: Arc::new(SeqInner {
                 fd,
                 client_id: client_id.0 as u32,
+                capabilities,
+                discovery,
             }),
         };
 
-        for client in seq.clients_iter() {
-            println!("client: {:?}", client.name);
-            for port in seq.ports_iter(client.client as u8) {
-                println!("port: {:?}", port.name);
-            }
-        }
-
         Ok((seq.clone(), SeqInput::new(seq.clone()), SeqOutput::new(seq)))
     }
 
+    /// Protocol versions and features detected on open
+    pub fn capabilities(&self) -> &Capabilities {
+        &self.inner.capabilities
+    }
+
+    /// How the device node was found
+    pub fn discovery(&self) -> DiscoveryMethod {
+        self.inner.discovery
+    }
+
+    /// Receive `KernelError` events for events the kernel failed to deliver
+    pub fn set_bounce_errors(&self, enable: bool) -> io::Result<()> {
+        let mut info = seq_ioctl::ClientInfo::new(self.inner.client_id as c_int);
+        seq_ioctl::get_client_info(self, &mut info)?;
+        info.filter.set(Filter::BOUNCE, enable);
+        seq_ioctl::set_client_info(self, info)?;
+
+        Ok(())
+    }
+
+    /// Only receive events of `kinds`, the kernel drops all others
+    ///
+    /// Like `snd_seq_set_client_event_filter` this lists the types to keep,
+    /// including announcements like `PortStart` if they are wanted.
+    pub fn set_event_filter(
+        &self,
+        kinds: impl IntoIterator<Item = event::EventKind>,
+    ) -> io::Result<()> {
+        let mut info = seq_ioctl::ClientInfo::new(self.inner.client_id as c_int);
+        seq_ioctl::get_client_info(self, &mut info)?;
+        info.event_filter = builder::event_filter_bitmap(kinds);
+        info.filter |= Filter::USE_EVENT;
+        seq_ioctl::set_client_info(self, info)?;
+
+        Ok(())
+    }
+
+    /// Receive events of all types again
+    pub fn clear_event_filter(&self) -> io::Result<()> {
+        let mut info = seq_ioctl::ClientInfo::new(self.inner.client_id as c_int);
+        seq_ioctl::get_client_info(self, &mut info)?;
+        info.event_filter = [0; 32];
+        info.filter.remove(Filter::USE_EVENT);
+        seq_ioctl::set_client_info(self, info)?;
+
+        Ok(())
+    }
+
+    /// Current pool sizes of this client
+    pub fn client_pool(&self) -> io::Result<seq_ioctl::ClientPool> {
+        let mut pool = seq_ioctl::ClientPool::new(self.inner.client_id as c_int);
+        seq_ioctl::get_client_pool(self, &mut pool)?;
+
+        Ok(pool)
+    }
+
+    /// Resize the pools of this client
+    ///
+    /// Changing the input pool drops the events waiting in it.
+    pub fn set_client_pool(&self, mut pool: seq_ioctl::ClientPool) -> io::Result<()> {
+        pool.client = self.inner.client_id as c_int;
+        seq_ioctl::set_client_pool(self, pool)?;
+
+        Ok(())
+    }
+
+    /// Resize the kernel input pool, in events
+    ///
+    /// Fails with `InvalidInput` if `size` does not fit a `c_int`.
+    pub fn set_input_pool(&self, size: usize) -> io::Result<()> {
+        let pool = self.client_pool()?.with_input_pool(pool_size(size)?);
+        self.set_client_pool(pool)
+    }
+
+    /// Resize the kernel output pool, in events
+    ///
+    /// Fails with `InvalidInput` if `size` does not fit a `c_int`.
+    pub fn set_output_pool(&self, size: usize) -> io::Result<()> {
+        let pool = self.client_pool()?.with_output_pool(pool_size(size)?);
+        self.set_client_pool(pool)
+    }
+
     pub fn create_port(&self, mut info: seq_ioctl::PortInfo) -> io::Result<Port> {
         info.addr.client = self.inner.client_id as u8;
 
@@ -98,26 +197,22 @@ impl Seq {
         capability: PortCapability,
         kind: PortType,
     ) -> io::Result<Port> {
-        let mut info: seq_ioctl::PortInfo = unsafe { std::mem::zeroed() };
+        let mut info = seq_ioctl::PortInfo::default()
+            .with_capability(capability)
+            .with_type(kind)
+            .with_midi_channels(16)
+            .with_midi_voices(64)
+            .with_synth_voices(0);
 
         for (src, dest) in name.to_bytes_with_nul().iter().zip(info.name.0.iter_mut()) {
             *dest = *src;
         }
 
-        info.capability = capability;
-        info.type_ = kind;
-        info.midi_channels = 16;
-        info.midi_voices = 64;
-        info.synth_voices = 0;
-
         self.create_port(info)
     }
 
     pub fn delete_port(&self, port: &Port) -> io::Result<()> {
-        let mut port_info: seq_ioctl::PortInfo = unsafe { std::mem::zeroed() };
-        port_info.addr = port.addr;
-
-        seq_ioctl::delete_port(self, port_info)?;
+        seq_ioctl::delete_port(self, seq_ioctl::PortInfo::new(port.addr))?;
 
         Ok(())
     }
@@ -131,6 +226,16 @@ impl Seq {
     }
 }
 
+/// `size` as the kernel's pool size type
+pub(crate) fn pool_size(size: usize) -> io::Result<c_int> {
+    c_int::try_from(size).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("pool size {} is too large", size),
+        )
+    })
+}
+
 impl AsRawFd for Seq {
     fn as_raw_fd(&self) -> RawFd {
         self.inner.fd.as_raw_fd()
@@ -149,24 +254,18 @@ pub struct Port {
 }
 
 impl Port {
-    pub fn connect_src(&self, seq: &Seq, src: Addr) -> io::Result<()> {
-        let mut data: seq_ioctl::PortSubscribe = unsafe { std::mem::zeroed() };
-
-        data.sender = src;
-        data.dest = self.addr;
+    pub fn addr(&self) -> Addr {
+        self.addr
+    }
 
-        seq_ioctl::subscribe_port(seq, data)?;
+    pub fn connect_src(&self, seq: &Seq, src: Addr) -> io::Result<()> {
+        seq_ioctl::subscribe_port(seq, seq_ioctl::PortSubscribe::new(src, self.addr))?;
 
         Ok(())
     }
 
     pub fn connect_dest(&self, seq: &Seq, dest: Addr) -> io::Result<()> {
-        let mut data: seq_ioctl::PortSubscribe = unsafe { std::mem::zeroed() };
-
-        data.sender = self.addr;
-        data.dest = dest;
-
-        seq_ioctl::subscribe_port(seq, data)?;
+        seq_ioctl::subscribe_port(seq, seq_ioctl::PortSubscribe::new(self.addr, dest))?;
 
         Ok(())
     }
@@ -179,8 +278,7 @@ pub struct ClientIter {
 
 impl ClientIter {
     fn new(seq: Seq) -> Self {
-        let mut client_info: seq_ioctl::ClientInfo = unsafe { std::mem::zeroed() };
-        client_info.client = -1;
+        let client_info = seq_ioctl::ClientInfo::new(-1);
         Self { seq, client_info }
     }
 
@@ -206,9 +304,7 @@ pub struct PortIter {
 
 impl PortIter {
     fn new(seq: Seq, client: u8) -> Self {
-        let mut port_info: seq_ioctl::PortInfo = unsafe { std::mem::zeroed() };
-        port_info.addr.client = client;
-        port_info.addr.port = u8::MAX;
+        let port_info = seq_ioctl::PortInfo::new(Addr::new(client, u8::MAX));
         Self { seq, port_info }
     }
 
@@ -1,17 +1,25 @@
 use std::{io, os::unix::prelude::AsRawFd};
 
-use rustix::fd::{AsFd, RawFd};
+use rustix::{
+    event::{PollFd, PollFlags},
+    fd::{AsFd, RawFd},
+    io::Errno,
+};
 
-use super::{event, Seq};
+use super::{event, Seq, SND_SEQ_OBUF_SIZE};
 
 #[derive(Debug)]
 pub struct SeqOutput {
     seq: Seq,
+    buffer: OutputBuffer,
 }
 
 impl SeqOutput {
     pub(crate) fn new(seq: Seq) -> Self {
-        Self { seq }
+        Self {
+            seq,
+            buffer: OutputBuffer::new(SND_SEQ_OBUF_SIZE),
+        }
     }
 
     pub fn seq(&self) -> &Seq {
@@ -21,17 +29,167 @@ impl SeqOutput {
     /// Output an event directly to the sequencer NOT through output buffer
     ///
     /// This function sends an event to the sequencer directly not through the
-    /// output buffer.  
+    /// output buffer. Variable length events (`Sysex`, `Bounce`, `UsrVar*`)
+    /// are written with their payload right after the event.
     pub fn send(&mut self, event: &event::Event) -> io::Result<()> {
-        let bytes = event.event_bytes();
-        let size = rustix::io::write(&self.seq, bytes)?;
+        let mut bytes = Vec::with_capacity(event.encoded_len());
+        self.sourced(event).encode(&mut bytes);
+        let size = rustix::io::write(&self.seq, &bytes)?;
 
         if bytes.len() != size {
-            unimplemented!("Message does not fit in the buffer");
+            return Err(io::ErrorKind::WriteZero.into());
         }
 
         Ok(())
     }
+
+    /// Output an event through the output buffer
+    ///
+    /// Like `snd_seq_event_output` the buffer is flushed first until the event
+    /// fits, which returns `WouldBlock` if the sequencer can't take more events
+    /// right now. The event is not buffered in that case. Returns the number of
+    /// bytes pending in the buffer.
+    pub fn output(&mut self, event: &event::Event) -> io::Result<usize> {
+        let event = self.sourced(event);
+        let seq = &self.seq;
+        self.buffer
+            .output(&event, |bytes| Ok(rustix::io::write(seq, bytes)?))
+    }
+
+    /// Bytes in the output buffer not yet written to the sequencer
+    pub fn pending(&self) -> usize {
+        self.buffer.pending()
+    }
+
+    pub fn buffer_size(&self) -> usize {
+        self.buffer.size()
+    }
+
+    /// Output buffer size in bytes, flushes when `output` would go past it
+    ///
+    /// Events larger than the buffer are still accepted into an empty buffer.
+    pub fn set_buffer_size(&mut self, size: usize) {
+        self.buffer.set_size(size);
+    }
+
+    /// Write as much of the output buffer as the sequencer takes in one `write`
+    ///
+    /// Returns the bytes left in the buffer, or `WouldBlock` if nothing could
+    /// be written.
+    pub fn flush(&mut self) -> io::Result<usize> {
+        let seq = &self.seq;
+        self.buffer
+            .flush(|bytes| Ok(rustix::io::write(seq, bytes)?))
+    }
+
+    /// Write the whole output buffer, waiting for the sequencer to take it
+    pub fn drain(&mut self) -> io::Result<()> {
+        while self.buffer.pending() != 0 {
+            match self.flush() {
+                Ok(_) => {}
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => {
+                    let mut fds = [PollFd::new(&self.seq, PollFlags::OUT)];
+                    match rustix::event::poll(&mut fds, -1) {
+                        Ok(_) | Err(Errno::INTR) => {}
+                        Err(err) => return Err(err.into()),
+                    }
+                }
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Drop the events in the output buffer
+    pub fn drop_output(&mut self) {
+        self.buffer.clear();
+    }
+
+    fn sourced<'e>(&self, event: &event::Event<'e>) -> event::Event<'e> {
+        let mut event = event.clone();
+        event.set_source_client(self.seq.inner.client_id as u8);
+        event
+    }
+}
+
+/// Encoded events waiting to be written, the buffer of `SeqOutput`
+///
+/// Written through a `write` function taking bytes like `write(2)`, so it
+/// works without a sequencer.
+#[derive(Debug)]
+pub struct OutputBuffer {
+    buffer: Vec<u8>,
+    size: usize,
+}
+
+impl OutputBuffer {
+    /// Empty buffer flushing past `size` bytes
+    pub fn new(size: usize) -> Self {
+        Self {
+            buffer: Vec::with_capacity(size),
+            size,
+        }
+    }
+
+    /// Append an event, flushing first until it fits
+    ///
+    /// Fails with `WouldBlock` without buffering the event when a flush
+    /// writes nothing. Returns the number of bytes pending.
+    pub fn output<W>(&mut self, event: &event::Event, mut write: W) -> io::Result<usize>
+    where
+        W: FnMut(&[u8]) -> io::Result<usize>,
+    {
+        let len = event.encoded_len();
+        while !self.buffer.is_empty() && self.buffer.len() + len > self.size {
+            let pending = self.buffer.len();
+            if self.flush(&mut write)? == pending {
+                return Err(io::ErrorKind::WouldBlock.into());
+            }
+        }
+
+        event.encode(&mut self.buffer);
+
+        Ok(self.buffer.len())
+    }
+
+    /// Write as much of the buffer as one `write` takes
+    ///
+    /// Returns the bytes left in the buffer.
+    pub fn flush<W>(&mut self, mut write: W) -> io::Result<usize>
+    where
+        W: FnMut(&[u8]) -> io::Result<usize>,
+    {
+        if self.buffer.is_empty() {
+            return Ok(0);
+        }
+
+        // the kernel only takes whole events, a short write ends on an event boundary
+        let size = write(&self.buffer)?;
+        self.buffer.drain(..size.min(self.buffer.len()));
+
+        Ok(self.buffer.len())
+    }
+
+    /// Bytes not yet written
+    pub fn pending(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Size in bytes, events larger than it are still accepted when empty
+    pub fn set_size(&mut self, size: usize) {
+        self.size = size;
+    }
+
+    /// Drop the buffered events
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
 }
 
 impl AsRawFd for SeqOutput {
@@ -0,0 +1,560 @@
+//! Parser for the text files under `/proc/asound`
+//!
+//! Works where udev doesn't, e.g. in containers, and shows a few things the
+//! ioctls don't expose easily like queue owners and the timer behind a queue.
+//! The formats are the ones printed by `sound/core/init.c` and
+//! `sound/core/seq/`; fields added by newer kernels are optional.
+
+use std::{
+    fs, io,
+    os::raw::{c_int, c_uint},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use alsa_ioctl::seq_ioctl::{
+    self, Addr, ClientType, MidiVersion, PortCapability, PortDirection, QueueId, SubscribeFlags,
+    TickTimeT,
+};
+
+/// `/proc/asound` or a copy of it
+#[derive(Debug, Clone)]
+pub struct ProcAsound {
+    root: PathBuf,
+}
+
+impl Default for ProcAsound {
+    fn default() -> Self {
+        Self::new("/proc/asound")
+    }
+}
+
+impl ProcAsound {
+    pub fn new<P: AsRef<Path>>(root: P) -> Self {
+        Self {
+            root: root.as_ref().to_owned(),
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// `cards`
+    pub fn cards(&self) -> io::Result<Vec<ProcCard>> {
+        parse_cards(&self.read("cards")?)
+    }
+
+    /// `seq/clients`
+    pub fn seq_clients(&self) -> io::Result<ProcSeqClients> {
+        parse_seq_clients(&self.read("seq/clients")?)
+    }
+
+    /// `seq/queues`
+    pub fn seq_queues(&self) -> io::Result<Vec<ProcSeqQueue>> {
+        parse_seq_queues(&self.read("seq/queues")?)
+    }
+
+    /// `seq/timer`
+    pub fn seq_timers(&self) -> io::Result<Vec<ProcSeqTimer>> {
+        parse_seq_timers(&self.read("seq/timer")?)
+    }
+
+    fn read(&self, file: &str) -> io::Result<String> {
+        fs::read_to_string(self.root.join(file))
+    }
+}
+
+/// Entry of `/proc/asound/cards`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcCard {
+    /// card number, as in `ClientInfo::card`
+    pub index: c_int,
+    /// `id` file of the card, e.g. `PCH`
+    pub id: String,
+    pub driver: String,
+    pub name: String,
+    pub long_name: String,
+}
+
+/// `/proc/asound/seq/clients`
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ProcSeqClients {
+    pub cur_clients: c_int,
+    pub peak_clients: c_int,
+    pub max_clients: c_int,
+    pub clients: Vec<ProcSeqClient>,
+}
+
+/// Client of `/proc/asound/seq/clients`, see `seq_ioctl::ClientInfo`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcSeqClient {
+    pub client: c_int,
+    pub name: String,
+    pub type_: ClientType,
+    /// `None` on kernels without UMP support
+    pub midi_version: Option<MidiVersion>,
+    /// active ports
+    pub ports: Vec<ProcSeqPort>,
+    pub output_pool: Option<ProcSeqPool>,
+    pub input_pool: Option<ProcSeqPool>,
+}
+
+impl ProcSeqClient {
+    /// `ClientInfo` with the fields known from `/proc`
+    pub fn client_info(&self) -> seq_ioctl::ClientInfo {
+        seq_ioctl::ClientInfo::new(self.client)
+            .with_name(&self.name)
+            .with_type(self.type_)
+            .with_midi_version(self.midi_version.unwrap_or(MidiVersion::LEGACY))
+    }
+}
+
+/// Port of `/proc/asound/seq/clients`, see `seq_ioctl::PortInfo`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcSeqPort {
+    pub addr: Addr,
+    pub name: String,
+    /// only the `READ`, `SUBS_READ`, `WRITE`, `SUBS_WRITE`, `NO_EXPORT` and
+    /// `DUPLEX` bits are shown
+    pub capability: PortCapability,
+    /// `None` on kernels before port directions were added
+    pub direction: Option<PortDirection>,
+    /// subscriptions reading from this port
+    pub connecting_to: Vec<ProcSeqSubscriber>,
+    /// subscriptions writing to this port
+    pub connected_from: Vec<ProcSeqSubscriber>,
+}
+
+impl ProcSeqPort {
+    /// `PortInfo` with the fields known from `/proc`
+    pub fn port_info(&self) -> seq_ioctl::PortInfo {
+        seq_ioctl::PortInfo::new(self.addr)
+            .with_name(&self.name)
+            .with_capability(self.capability)
+            .with_direction(self.direction.unwrap_or(PortDirection::UNKNOWN))
+    }
+}
+
+/// Other end of a subscription, see `seq_ioctl::QuerySubscribe`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcSeqSubscriber {
+    pub addr: Addr,
+    pub flags: SubscribeFlags,
+    /// only meaningful with `SubscribeFlags::TIMESTAMP`
+    pub queue: QueueId,
+}
+
+/// Event pool statistics of a client
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ProcSeqPool {
+    pub size: c_int,
+    pub cells_in_use: c_int,
+    pub peak_cells_in_use: c_int,
+    pub alloc_success: c_int,
+    pub alloc_failures: c_int,
+}
+
+/// Entry of `/proc/asound/seq/queues`, see `seq_ioctl::QueueInfo`,
+/// `QueueStatus` and `QueueTempo`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcSeqQueue {
+    pub queue: QueueId,
+    pub name: String,
+    /// owning client
+    pub owner: c_int,
+    pub locked: bool,
+    pub queued_time_events: c_int,
+    pub queued_tick_events: c_int,
+    pub running: bool,
+    pub ppq: c_int,
+    /// µs per quarter note
+    pub tempo: c_uint,
+    /// ns, `None` on kernels without tempo base support
+    pub tempo_base: Option<c_uint>,
+    pub bpm: c_uint,
+    pub time: Duration,
+    pub tick: TickTimeT,
+}
+
+/// Entry of `/proc/asound/seq/timer`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcSeqTimer {
+    pub queue: QueueId,
+    /// name of the ALSA timer driving the queue
+    pub name: String,
+    /// timer period times ticks per period
+    pub period: Duration,
+    pub skew_value: c_uint,
+    pub skew_base: c_uint,
+}
+
+/// Parse the contents of `/proc/asound/cards`
+pub fn parse_cards(text: &str) -> io::Result<Vec<ProcCard>> {
+    let mut cards = Vec::new();
+    let mut lines = text.lines().enumerate();
+
+    while let Some((n, line)) = lines.next() {
+        if line.trim().is_empty() || line.trim() == "--- no soundcards ---" {
+            continue;
+        }
+
+        // "%2i [%-15s]: %s - %s"
+        let (index, rest) = line
+            .split_once('[')
+            .ok_or_else(|| invalid(n, "expected `[`"))?;
+        let (id, rest) = rest
+            .split_once("]: ")
+            .ok_or_else(|| invalid(n, "expected `]: `"))?;
+        let (driver, name) = rest
+            .split_once(" - ")
+            .ok_or_else(|| invalid(n, "expected ` - `"))?;
+        let long_name = lines
+            .next()
+            .map(|(_, line)| line.trim())
+            .unwrap_or_default();
+
+        cards.push(ProcCard {
+            index: number(n, index)?,
+            id: id.trim_end().to_owned(),
+            driver: driver.to_owned(),
+            name: name.to_owned(),
+            long_name: long_name.to_owned(),
+        });
+    }
+
+    Ok(cards)
+}
+
+/// Parse the contents of `/proc/asound/seq/clients`
+pub fn parse_seq_clients(text: &str) -> io::Result<ProcSeqClients> {
+    enum Pool {
+        None,
+        Output,
+        Input,
+    }
+
+    let mut clients = ProcSeqClients::default();
+    let mut pool = Pool::None;
+
+    for (n, line) in text.lines().enumerate() {
+        let trimmed = line.trim();
+
+        if let Some(rest) = trimmed
+            .strip_prefix("Client ")
+            .filter(|_| trimmed != "Client info")
+        {
+            clients.clients.push(parse_client(n, rest)?);
+            pool = Pool::None;
+        } else if let Some(rest) = trimmed.strip_prefix("Port ") {
+            let client = clients
+                .clients
+                .last_mut()
+                .ok_or_else(|| invalid(n, "port outside of a client"))?;
+            let port = parse_port(n, client.client, rest)?;
+            client.ports.push(port);
+        } else if let Some(rest) = trimmed.strip_prefix("Connecting To:") {
+            last_port(n, &mut clients)?.connecting_to = parse_subscribers(n, rest)?;
+        } else if let Some(rest) = trimmed.strip_prefix("Connected From:") {
+            last_port(n, &mut clients)?.connected_from = parse_subscribers(n, rest)?;
+        } else if trimmed == "Output pool :" {
+            pool = Pool::Output;
+        } else if trimmed == "Input pool :" {
+            pool = Pool::Input;
+        } else if let Some((key, value)) = key_value(trimmed) {
+            let pool = match pool {
+                Pool::Output => clients.clients.last_mut().map(|c| &mut c.output_pool),
+                Pool::Input => clients.clients.last_mut().map(|c| &mut c.input_pool),
+                Pool::None => None,
+            };
+
+            if let Some(pool) = pool {
+                let pool = pool.get_or_insert_with(Default::default);
+                match key.as_str() {
+                    "Pool size" => pool.size = number(n, value)?,
+                    "Cells in use" => pool.cells_in_use = number(n, value)?,
+                    "Peak cells in use" => pool.peak_cells_in_use = number(n, value)?,
+                    "Alloc success" => pool.alloc_success = number(n, value)?,
+                    "Alloc failures" => pool.alloc_failures = number(n, value)?,
+                    _ => {}
+                }
+            } else {
+                match key.as_str() {
+                    "cur clients" => clients.cur_clients = number(n, value)?,
+                    "peak clients" => clients.peak_clients = number(n, value)?,
+                    "max clients" => clients.max_clients = number(n, value)?,
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    Ok(clients)
+}
+
+/// Parse the contents of `/proc/asound/seq/queues`
+pub fn parse_seq_queues(text: &str) -> io::Result<Vec<ProcSeqQueue>> {
+    let mut queues: Vec<ProcSeqQueue> = Vec::new();
+
+    for (n, line) in text.lines().enumerate() {
+        if let Some(rest) = line.strip_prefix("queue ") {
+            // "queue %d: [%s]"
+            let (queue, name) = rest
+                .split_once(": [")
+                .ok_or_else(|| invalid(n, "expected `: [`"))?;
+            let name = name
+                .strip_suffix(']')
+                .ok_or_else(|| invalid(n, "expected `]`"))?;
+
+            queues.push(ProcSeqQueue {
+                queue: QueueId(number(n, queue)?),
+                name: name.to_owned(),
+                owner: 0,
+                locked: false,
+                queued_time_events: 0,
+                queued_tick_events: 0,
+                running: false,
+                ppq: 0,
+                tempo: 0,
+                tempo_base: None,
+                bpm: 0,
+                time: Duration::ZERO,
+                tick: 0,
+            });
+            continue;
+        }
+
+        let Some((key, value)) = key_value(line) else {
+            continue;
+        };
+        let queue = queues
+            .last_mut()
+            .ok_or_else(|| invalid(n, "value outside of a queue"))?;
+
+        match key.as_str() {
+            "owned by client" => queue.owner = number(n, value)?,
+            "lock status" => queue.locked = value == "Locked",
+            "queued time events" => queue.queued_time_events = number(n, value)?,
+            "queued tick events" => queue.queued_tick_events = number(n, value)?,
+            "timer state" => queue.running = value == "Running",
+            "timer PPQ" => queue.ppq = number(n, value)?,
+            "current tempo" => queue.tempo = number(n, value)?,
+            "tempo base" => queue.tempo_base = Some(number(n, value.trim_end_matches("ns"))?),
+            "current BPM" => queue.bpm = number(n, value)?,
+            "current time" => queue.time = duration(n, value.trim_end_matches('s'))?,
+            "current tick" => queue.tick = number(n, value)?,
+            _ => {}
+        }
+    }
+
+    Ok(queues)
+}
+
+/// Parse the contents of `/proc/asound/seq/timer`
+pub fn parse_seq_timers(text: &str) -> io::Result<Vec<ProcSeqTimer>> {
+    let mut timers: Vec<ProcSeqTimer> = Vec::new();
+
+    for (n, line) in text.lines().enumerate() {
+        if let Some(rest) = line.strip_prefix("Timer for queue ") {
+            // "Timer for queue %i : %s"
+            let (queue, name) = rest
+                .split_once(" : ")
+                .ok_or_else(|| invalid(n, "expected ` : `"))?;
+
+            timers.push(ProcSeqTimer {
+                queue: QueueId(number(n, queue)?),
+                name: name.to_owned(),
+                period: Duration::ZERO,
+                skew_value: 0,
+                skew_base: 0,
+            });
+            continue;
+        }
+
+        let Some((key, value)) = key_value(line) else {
+            continue;
+        };
+        let timer = timers
+            .last_mut()
+            .ok_or_else(|| invalid(n, "value outside of a timer"))?;
+
+        match key.as_str() {
+            "Period time" => timer.period = duration(n, value)?,
+            "Skew" => {
+                // "%u / %u"
+                let (value, base) = value
+                    .split_once('/')
+                    .ok_or_else(|| invalid(n, "expected `/`"))?;
+                timer.skew_value = number(n, value)?;
+                timer.skew_base = number(n, base)?;
+            }
+            _ => {}
+        }
+    }
+
+    Ok(timers)
+}
+
+/// "Client %3d : \"%s\" [%s %s]", the MIDI version is missing on older kernels
+fn parse_client(n: usize, rest: &str) -> io::Result<ProcSeqClient> {
+    let (client, rest) = rest
+        .split_once(" : \"")
+        .ok_or_else(|| invalid(n, "expected ` : \"`"))?;
+    let (name, kind) = rest
+        .rsplit_once("\" [")
+        .ok_or_else(|| invalid(n, "expected `\" [`"))?;
+    let kind = kind
+        .strip_suffix(']')
+        .ok_or_else(|| invalid(n, "expected `]`"))?;
+    let (type_, midi_version) = kind.split_once(' ').unwrap_or((kind, ""));
+
+    let type_ = match type_ {
+        "User" => ClientType::USER_CLIENT,
+        "Kernel" => ClientType::KERNEL_CLIENT,
+        _ => return Err(invalid(n, "unknown client type")),
+    };
+    let midi_version = match midi_version {
+        "" => None,
+        "Legacy" => Some(MidiVersion::LEGACY),
+        "UMP MIDI1" => Some(MidiVersion::UMP_MIDI_1_0),
+        "UMP MIDI2" => Some(MidiVersion::UMP_MIDI_2_0),
+        _ => return Err(invalid(n, "unknown MIDI version")),
+    };
+
+    Ok(ProcSeqClient {
+        client: number(n, client)?,
+        name: name.to_owned(),
+        type_,
+        midi_version,
+        ports: Vec::new(),
+        output_pool: None,
+        input_pool: None,
+    })
+}
+
+/// "Port %3d : \"%s\" (%c%c%c%c) [%s]", the direction is missing on older kernels
+fn parse_port(n: usize, client: c_int, rest: &str) -> io::Result<ProcSeqPort> {
+    let (port, rest) = rest
+        .split_once(" : \"")
+        .ok_or_else(|| invalid(n, "expected ` : \"`"))?;
+    let (name, rest) = rest
+        .rsplit_once("\" (")
+        .ok_or_else(|| invalid(n, "expected `\" (`"))?;
+    let (flags, direction) = rest
+        .split_once(')')
+        .ok_or_else(|| invalid(n, "expected `)`"))?;
+
+    let mut capability = PortCapability::empty();
+    let flags = flags.as_bytes();
+    if flags.len() != 4 {
+        return Err(invalid(n, "expected 4 capability flags"));
+    }
+    match flags[0] {
+        b'R' => capability |= PortCapability::READ | PortCapability::SUBS_READ,
+        b'r' => capability |= PortCapability::READ,
+        _ => {}
+    }
+    match flags[1] {
+        b'W' => capability |= PortCapability::WRITE | PortCapability::SUBS_WRITE,
+        b'w' => capability |= PortCapability::WRITE,
+        _ => {}
+    }
+    if flags[2] != b'e' {
+        capability |= PortCapability::NO_EXPORT;
+    }
+    if flags[3] == b'X' {
+        capability |= PortCapability::DUPLEX;
+    }
+
+    let direction = match direction.trim() {
+        "" => None,
+        "[-]" => Some(PortDirection::UNKNOWN),
+        "[In]" => Some(PortDirection::INPUT),
+        "[Out]" => Some(PortDirection::OUTPUT),
+        "[In/Out]" => Some(PortDirection::BIDIRECTION),
+        _ => return Err(invalid(n, "unknown port direction")),
+    };
+
+    Ok(ProcSeqPort {
+        // client numbers are below `SNDRV_SEQ_MAX_CLIENTS`
+        addr: Addr::new(client as u8, number(n, port)?),
+        name: name.to_owned(),
+        capability,
+        direction,
+        connecting_to: Vec::new(),
+        connected_from: Vec::new(),
+    })
+}
+
+/// "%d:%d" with optional "[t:%d]"/"[r:%d]" and "[ex]", separated by ", "
+fn parse_subscribers(n: usize, rest: &str) -> io::Result<Vec<ProcSeqSubscriber>> {
+    rest.split(", ")
+        .map(|entry| {
+            let (addr, mut options) = entry.trim().split_once('[').unwrap_or((entry.trim(), ""));
+            let (client, port) = addr
+                .split_once(':')
+                .ok_or_else(|| invalid(n, "expected `client:port`"))?;
+
+            let mut subscriber = ProcSeqSubscriber {
+                addr: Addr::new(number(n, client)?, number(n, port)?),
+                flags: SubscribeFlags::empty(),
+                queue: QueueId(0),
+            };
+
+            while let Some((option, rest)) = options.split_once(']') {
+                if option == "ex" {
+                    subscriber.flags |= SubscribeFlags::EXCLUSIVE;
+                } else if let Some((kind, queue)) = option.split_once(':') {
+                    subscriber.flags |= SubscribeFlags::TIMESTAMP;
+                    if kind == "r" {
+                        subscriber.flags |= SubscribeFlags::TIME_REAL;
+                    }
+                    subscriber.queue = QueueId(number(n, queue)?);
+                } else {
+                    return Err(invalid(n, "unknown subscription option"));
+                }
+                options = rest.strip_prefix('[').unwrap_or(rest);
+            }
+
+            Ok(subscriber)
+        })
+        .collect()
+}
+
+fn last_port(n: usize, clients: &mut ProcSeqClients) -> io::Result<&mut ProcSeqPort> {
+    clients
+        .clients
+        .last_mut()
+        .and_then(|client| client.ports.last_mut())
+        .ok_or_else(|| invalid(n, "subscribers outside of a port"))
+}
+
+/// `key : value` with runs of spaces in the key collapsed
+fn key_value(line: &str) -> Option<(String, &str)> {
+    let (key, value) = line.split_once(':')?;
+    let key = key.split_whitespace().collect::<Vec<_>>().join(" ");
+    Some((key, value.trim()))
+}
+
+fn number<T: std::str::FromStr>(n: usize, value: &str) -> io::Result<T> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| invalid(n, &format!("expected a number, got `{}`", value.trim())))
+}
+
+/// "%lu.%09lu" seconds
+fn duration(n: usize, value: &str) -> io::Result<Duration> {
+    let (secs, nanos) = value
+        .trim()
+        .split_once('.')
+        .ok_or_else(|| invalid(n, "expected `secs.nanos`"))?;
+    Ok(Duration::new(number(n, secs)?, number(n, nanos)?))
+}
+
+fn invalid(n: usize, msg: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("line {}: {}", n + 1, msg),
+    )
+}
@@ -0,0 +1,72 @@
+use std::{
+    fs, io,
+    os::unix::fs::{FileTypeExt, MetadataExt},
+    path::PathBuf,
+};
+
+use ralsa_seq::query_sysfs;
+
+/// `dev` file in its own temp dir, removed on drop
+struct DevFile {
+    dir: PathBuf,
+}
+
+impl DevFile {
+    fn new(name: &str, contents: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!(
+            "ralsa-seq-discovery-{}-{}",
+            std::process::id(),
+            name
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("dev"), contents).unwrap();
+        Self { dir }
+    }
+
+    fn path(&self) -> PathBuf {
+        self.dir.join("dev")
+    }
+}
+
+impl Drop for DevFile {
+    fn drop(&mut self) {
+        fs::remove_dir_all(&self.dir).ok();
+    }
+}
+
+#[test]
+fn query_sysfs_found() {
+    // `/dev/null` is 1:3 on every Linux system, though a link to it like
+    // `/dev/stdin` may be found first
+    let dev = DevFile::new("found", "1:3\n");
+    let path = query_sysfs(dev.path(), "/dev").unwrap();
+
+    let meta = fs::metadata(&path).unwrap();
+    assert!(meta.file_type().is_char_device(), "{path:?}");
+    assert_eq!(meta.rdev(), fs::metadata("/dev/null").unwrap().rdev());
+}
+
+#[test]
+fn query_sysfs_malformed() {
+    for (name, contents) in [
+        ("empty", ""),
+        ("no-colon", "116"),
+        ("not-number", "116:x"),
+        ("negative", "-1:3"),
+    ] {
+        let dev = DevFile::new(name, contents);
+        let err = query_sysfs(dev.path(), "/dev").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData, "{contents:?}");
+    }
+}
+
+#[test]
+fn query_sysfs_not_found() {
+    // no character devices in the temp dir itself
+    let dev = DevFile::new("not-found", "1:3");
+    let err = query_sysfs(dev.path(), &dev.dir).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+
+    let err = query_sysfs(dev.dir.join("missing"), "/dev").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+}
@@ -0,0 +1,436 @@
+use std::time::Duration;
+
+use alsa_ioctl::seq_ioctl::{self, Addr, Connect, EvCtrl, EvNote, EvResult, QueueId, QueueSkew};
+use ralsa_seq::event::{Event, EventKind, EventTime, EventWithData, QuotedEvent, RawEventData};
+
+/// One value of every `EventWithData` variant
+fn samples() -> Vec<EventWithData<'static>> {
+    use EventWithData as E;
+
+    let note = EvNote::new(1, 60, 100)
+        .with_off_velocity(64)
+        .with_duration(480);
+    let ctrl = EvCtrl::new(2, 7, -100);
+    let result = EvResult {
+        event: 6,
+        result: -22,
+    };
+    let queue = QueueId(3);
+    let addr = Addr::new(24, 1);
+    let connect = Connect::new(Addr::new(24, 0), Addr::new(128, 2));
+    let d = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+    let bytes: &'static [u8] = &[0xf0, 0x7e, 0x7f, 0x06, 0x01, 0xf7];
+    let quote = QuotedEvent {
+        origin: Addr::new(130, 0),
+        value: 11,
+    };
+
+    vec![
+        E::System(result),
+        E::Result(result),
+        E::Note(note),
+        E::NoteOn(note),
+        E::NoteOff(note),
+        E::KeyPress(note),
+        E::Controller(ctrl),
+        E::Pgmchange(ctrl),
+        E::Chanpress(ctrl),
+        E::Pitchbend(ctrl),
+        E::Control14(ctrl),
+        E::Nonregparam(ctrl),
+        E::Regparam(ctrl),
+        E::Songpos(ctrl),
+        E::Songsel(ctrl),
+        E::Qframe(ctrl),
+        E::Timesign(ctrl),
+        E::Keysign(ctrl),
+        E::Start {
+            queue,
+            param: [7, 0],
+        },
+        E::Continue {
+            queue,
+            param: [7, 0],
+        },
+        E::Stop {
+            queue,
+            param: [7, 0],
+        },
+        E::SetposTick {
+            queue,
+            position: 1920,
+        },
+        E::SetposTime {
+            queue,
+            position: Duration::new(3, 250_000_000),
+        },
+        E::Tempo {
+            queue,
+            value: 500_000,
+        },
+        E::Clock {
+            queue,
+            param: [0x1234, 0x5678],
+        },
+        E::Tick {
+            queue,
+            position: 384,
+        },
+        E::QueueSkew {
+            queue,
+            skew: QueueSkew {
+                value: 70000,
+                base: 65536,
+            },
+        },
+        E::SyncPos {
+            queue,
+            position: 96,
+        },
+        E::TuneRequest(d),
+        E::Reset([0; 12]),
+        E::Sensing(d),
+        E::Echo(d),
+        E::Oss(d),
+        E::ClientStart(addr),
+        E::ClientExit(addr),
+        E::ClientChange(addr),
+        E::PortStart(addr),
+        E::PortExit(addr),
+        E::PortChange(addr),
+        E::PortSubscribed(connect),
+        E::PortUnsubscribed(connect),
+        E::Usr0(d),
+        E::Usr1(d),
+        E::Usr2(d),
+        E::Usr3(d),
+        E::Usr4(d),
+        E::Usr5(d),
+        E::Usr6(d),
+        E::Usr7(d),
+        E::Usr8(d),
+        E::Usr9(d),
+        E::Sysex(bytes),
+        E::Bounce(bytes),
+        E::UsrVar0(bytes),
+        E::UsrVar1(bytes),
+        E::UsrVar2(bytes),
+        E::UsrVar3(bytes),
+        E::UsrVar4(bytes),
+        E::KernelError(quote),
+        E::KernelQuote(quote),
+        E::None(d),
+        E::Unknown {
+            type_: 200,
+            data: RawEventData::Fixed(d),
+        },
+        E::Unknown {
+            type_: 255,
+            data: RawEventData::Variable(bytes),
+        },
+        // a known type with data not matching it
+        E::Unknown {
+            type_: seq_ioctl::EventType::NOTEON.0,
+            data: RawEventData::Variable(bytes),
+        },
+    ]
+}
+
+/// Header and payload as the kernel puts them in the read buffer
+fn wire(event: &Event) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    event.encode(&mut bytes);
+    assert_eq!(bytes.len(), event.encoded_len());
+    bytes
+}
+
+#[test]
+fn event_with_data_round_trip() {
+    for sample in samples() {
+        let kind = sample.kind();
+        let event = Event::from(sample);
+        assert_eq!(*event.kind(), kind);
+
+        let bytes = wire(&event);
+        let read = Event::read(&bytes).unwrap();
+        assert_eq!(*read.kind(), kind);
+
+        let rebuilt = Event::from(read.event_with_data());
+        assert_eq!(wire(&rebuilt), bytes, "{:?}", kind);
+    }
+}
+
+#[test]
+fn event_with_data_keeps_header() {
+    for sample in samples() {
+        let event = Event::from(sample)
+            .with_source_port(3)
+            .with_dest(Addr::new(130, 1))
+            .with_queue(QueueId(1))
+            .with_relative_time(EventTime::Time(Duration::from_millis(1500)))
+            .with_tag(-7)
+            .with_priority_high(true);
+
+        let bytes = wire(&event);
+        let read = Event::read(&bytes).unwrap();
+
+        let rebuilt = read.clone().with_event_data(read.event_with_data());
+        assert_eq!(wire(&rebuilt), bytes, "{:?}", event.kind());
+    }
+}
+
+/// Fixed size event of any type byte, with `data` after the header
+fn raw_event(type_: u8, data: [u8; 12]) -> Vec<u8> {
+    let mut bytes = wire(&Event::new(EventKind::Noteon));
+    bytes[0] = type_;
+    bytes[16..].copy_from_slice(&data);
+    bytes
+}
+
+#[test]
+fn raw_round_trip() {
+    use seq_ioctl::EventType as T;
+
+    let data = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+    // queue 3, padding, then an unused but set param
+    let queue = [3, 0, 0, 0, 0x78, 0x56, 0x34, 0x12, 1, 0, 0, 0];
+
+    let mut samples = vec![
+        raw_event(T::START.0, queue),
+        raw_event(T::CONTINUE.0, queue),
+        raw_event(T::STOP.0, queue),
+        raw_event(T::CLOCK.0, queue),
+        raw_event(T::TUNE_REQUEST.0, data),
+        raw_event(T::RESET.0, data),
+        raw_event(T::SENSING.0, data),
+        raw_event(T::NONE.0, data),
+    ];
+    for type_ in 0..=u8::MAX {
+        if EventKind::from(type_) == EventKind::None && !T(type_).is_variable_type() {
+            samples.push(raw_event(type_, data));
+        }
+    }
+    let mut unknown_ext = wire(&Event::sysex(&[0xf0, 1, 2, 0xf7][..]));
+    // 132-134 are reserved variable length types
+    unknown_ext[0] = 133;
+    let read = Event::read(&unknown_ext).unwrap();
+    assert!(matches!(
+        read.event_with_data(),
+        EventWithData::Unknown {
+            type_: 133,
+            data: RawEventData::Variable([0xf0, 1, 2, 0xf7])
+        }
+    ));
+    samples.push(unknown_ext);
+
+    for bytes in samples {
+        let read = Event::read(&bytes).unwrap();
+
+        let rebuilt = read.clone().with_event_data(read.event_with_data());
+        assert_eq!(wire(&rebuilt), bytes, "{:?}", read.event_with_data());
+        assert_eq!(wire(&Event::from(read.event_with_data())), bytes);
+    }
+}
+
+#[test]
+fn kind_and_data_round_trip() {
+    for sample in samples() {
+        // an unknown type has no `EventKind`
+        if matches!(sample, EventWithData::Unknown { type_, .. } if EventKind::from(type_) == EventKind::None)
+        {
+            continue;
+        }
+
+        let event = Event::from(sample);
+        let rebuilt = Event::from((*event.kind(), event.data()));
+        assert_eq!(wire(&rebuilt), wire(&event), "{:?}", event.kind());
+    }
+}
+
+#[test]
+fn typed_constructors() {
+    let event = Event::note_on(9, 36, 127);
+    assert_eq!(*event.kind(), EventKind::Noteon);
+    assert!(matches!(
+        event.event_with_data(),
+        EventWithData::NoteOn(EvNote {
+            channel: 9,
+            note: 36,
+            velocity: 127,
+            ..
+        })
+    ));
+
+    let event = Event::sysex(vec![0xf0, 0x01, 0xf7]);
+    assert!(matches!(
+        event.event_with_data(),
+        EventWithData::Sysex(&[0xf0, 0x01, 0xf7])
+    ));
+
+    let event = Event::pitchbend(0, -8192).with_time(EventTime::Tick(96));
+    assert!(matches!(event.time(), EventTime::Tick(96)));
+    assert!(!event.is_priority_high());
+    assert_eq!(event.queue(), seq_ioctl::QueueId::DIRECT);
+}
+
+#[test]
+fn variable_length_encoding() {
+    let payload = [0xf0, 0x43, 0x10, 0x4c, 0x00, 0x00, 0x7e, 0x00, 0xf7];
+    let event = Event::sysex(&payload[..]);
+
+    let bytes = wire(&event);
+    let header = std::mem::size_of::<seq_ioctl::Event>();
+    assert_eq!(bytes.len(), header + payload.len());
+    assert_eq!(&bytes[header..], &payload);
+
+    let read = Event::read(&bytes).unwrap();
+    assert!(matches!(
+        read.data(),
+        ralsa_seq::event::EventData::Ext(ext) if ext.len() == 9
+    ));
+
+    // variable kinds never go out as fixed size events
+    assert_eq!(Event::new(EventKind::UsrVar2).encoded_len(), header);
+    assert!(matches!(
+        Event::new(EventKind::UsrVar2).event_with_data(),
+        EventWithData::UsrVar2(&[])
+    ));
+}
+
+/// `EventBounce` followed by the payload of the bounced event
+fn bounce_payload(err: i32, event: &Event) -> Vec<u8> {
+    let mut payload = err.to_ne_bytes().to_vec();
+    event.encode(&mut payload);
+    payload
+}
+
+#[test]
+fn bounced_events() {
+    let original = Event::controller(3, 74, 90).with_dest(Addr::new(130, 0));
+    let bounce = Event::new(EventKind::Bounce).with_ext(bounce_payload(-11, &original));
+
+    let bounced = bounce.bounced().unwrap().unwrap();
+    assert_eq!(bounced.error, rustix::io::Errno::AGAIN);
+    assert_eq!(wire(&bounced.original), wire(&original));
+
+    let original = Event::sysex(&[0xf0, 0x01, 0x02, 0xf7][..]);
+    let bounce = Event::new(EventKind::Bounce).with_ext(bounce_payload(22, &original));
+    let bounced = bounce.bounced().unwrap().unwrap();
+    assert_eq!(bounced.error, rustix::io::Errno::INVAL);
+    assert!(matches!(
+        bounced.original.event_with_data(),
+        EventWithData::Sysex(&[0xf0, 0x01, 0x02, 0xf7])
+    ));
+
+    // the payload of the bounced sysex is cut short
+    let mut payload = bounce_payload(22, &original);
+    payload.pop();
+    let bounce = Event::new(EventKind::Bounce).with_ext(payload);
+    assert!(bounce.bounced().unwrap().is_err());
+
+    let bounce = Event::new(EventKind::Bounce).with_ext(vec![0; 8]);
+    assert!(bounce.bounced().unwrap().is_err());
+
+    // any client can send a bounce, the error is not trusted
+    for err in [i32::MIN, 0, 4096] {
+        let bounce = Event::new(EventKind::Bounce).with_ext(bounce_payload(err, &original));
+        let err = bounce.bounced().unwrap().unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+    let bounce = Event::new(EventKind::Bounce).with_ext(bounce_payload(-4095, &original));
+    assert!(bounce.bounced().unwrap().is_ok());
+
+    assert!(original.bounced().is_none());
+}
+
+#[test]
+fn kernel_error_quote() {
+    let quote = QuotedEvent {
+        origin: Addr::new(130, 0),
+        value: 32,
+    };
+    let event = Event::from(EventWithData::KernelError(quote));
+
+    let bytes = wire(&event);
+    let read = Event::read(&bytes).unwrap();
+
+    assert_eq!(*read.kind(), EventKind::KernelError);
+    assert_eq!(read.quote(), Some(quote));
+    assert_eq!(read.quote().unwrap().error(), Some(rustix::io::Errno::PIPE));
+    assert_eq!(QuotedEvent { value: 0, ..quote }.error(), None);
+    assert_eq!(Event::note_on(0, 1, 2).quote(), None);
+}
+
+/// xorshift, enough to make up buffers without a fuzzing crate
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn bytes(&mut self, len: usize) -> Vec<u8> {
+        (0..len).map(|_| self.next() as u8).collect()
+    }
+}
+
+const HEADER: usize = std::mem::size_of::<seq_ioctl::Event>();
+
+#[test]
+fn read_arbitrary_buffers() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+    for _ in 0..20_000 {
+        let len = rng.next() as usize % (3 * HEADER);
+        let mut bytes = rng.bytes(len);
+        if len > HEADER && rng.next() & 1 == 0 {
+            // variable length flag, with a payload length that may fit
+            bytes[1] = seq_ioctl::EventFlags::EVENT_LENGTH_VARIABLE;
+            let ext_len = rng.next() as u32 % (2 * HEADER as u32);
+            bytes[16..20].copy_from_slice(&ext_len.to_ne_bytes());
+        }
+
+        match Event::read(&bytes) {
+            Ok(event) => {
+                assert!(len >= HEADER);
+                if let ralsa_seq::event::EventData::Ext(ext) = event.data() {
+                    assert!(HEADER + ext.len() <= len);
+                }
+                let _ = event.event_with_data();
+            }
+            Err(err) => assert_eq!(err.kind(), std::io::ErrorKind::InvalidData),
+        }
+    }
+}
+
+#[test]
+fn read_truncated() {
+    for sample in samples() {
+        let bytes = wire(&Event::from(sample));
+
+        for len in 0..bytes.len() {
+            let err = Event::read(&bytes[..len]).unwrap_err();
+            assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        }
+        // trailing bytes belong to the next event
+        let mut padded = bytes.clone();
+        padded.extend_from_slice(&[0xff; 7]);
+        assert_eq!(wire(&Event::read(&padded).unwrap()), bytes);
+    }
+}
+
+#[test]
+fn read_unaligned() {
+    for sample in samples() {
+        let bytes = wire(&Event::from(sample));
+
+        for shift in 1..8 {
+            let mut buf = vec![0; shift];
+            buf.extend_from_slice(&bytes);
+            assert_eq!(wire(&Event::read(&buf[shift..]).unwrap()), bytes);
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use std::os::raw::c_int;
+
+use alsa_ioctl::seq_ioctl::{Addr, ClientInfo, PortInfo};
+use ralsa_seq::{Card, HotplugEvent, HotplugState};
+
+fn client(id: c_int, card: c_int) -> ClientInfo {
+    let mut info = ClientInfo::new(id);
+    info.card = card;
+    info
+}
+
+fn card(index: c_int, clients: &[c_int]) -> Card {
+    Card {
+        index,
+        name: format!("Card {index}"),
+        long_name: String::new(),
+        vendor: None,
+        vendor_id: None,
+        model: None,
+        model_id: None,
+        serial: None,
+        bus: None,
+        location: None,
+        path: None,
+        clients: clients.iter().map(|id| client(*id, index)).collect(),
+    }
+}
+
+fn drain(state: &mut HotplugState) -> Vec<HotplugEvent> {
+    std::iter::from_fn(|| state.next_event()).collect()
+}
+
+fn client_ids(clients: &[ClientInfo]) -> Vec<c_int> {
+    clients.iter().map(|client| client.client).collect()
+}
+
+#[test]
+fn udev_first() {
+    let mut state = HotplugState::new(true, [], []);
+
+    state.card_added(card(1, &[20]));
+    state.client_started(client(20, 1), None);
+    state.port_started(PortInfo::new(Addr::new(20, 0)));
+
+    let events = drain(&mut state);
+    assert_eq!(events.len(), 2);
+    assert!(matches!(&events[0], HotplugEvent::CardAdded(card) if card.index == 1));
+    assert!(matches!(&events[1], HotplugEvent::PortAdded(info) if info.addr == Addr::new(20, 0)));
+
+    // the card is removed with its clients, they are not reported again
+    state.card_removed(1);
+    state.client_exited(20);
+
+    let events = drain(&mut state);
+    assert_eq!(events.len(), 1);
+    assert!(
+        matches!(&events[0], HotplugEvent::CardRemoved(card) if client_ids(&card.clients) == [20])
+    );
+}
+
+#[test]
+fn announce_first() {
+    let mut state = HotplugState::new(true, [], []);
+
+    // kernel clients start before udev reports the card
+    state.client_started(client(20, 1), Some(card(1, &[20])));
+    state.client_started(client(21, 1), None);
+    state.port_started(PortInfo::new(Addr::new(20, 0)));
+    assert!(drain(&mut state).is_empty());
+    assert!(!state.has_card(1));
+
+    let mut udev_card = card(1, &[20]);
+    udev_card.vendor = Some("Focusrite-Novation".to_owned());
+    state.card_added(udev_card);
+    state.client_started(client(21, 1), None);
+
+    let events = drain(&mut state);
+    assert_eq!(events.len(), 1);
+    assert!(matches!(
+        &events[0],
+        HotplugEvent::CardAdded(card)
+            if client_ids(&card.clients) == [20, 21] && card.vendor.is_some()
+    ));
+    assert!(state.has_client(21));
+
+    // kernel clients exit before udev reports the removal
+    state.client_exited(20);
+    state.client_exited(21);
+    assert!(drain(&mut state).is_empty());
+    assert!(state.has_card(1));
+
+    state.card_removed(1);
+
+    let events = drain(&mut state);
+    assert_eq!(events.len(), 1);
+    assert!(
+        matches!(&events[0], HotplugEvent::CardRemoved(card) if client_ids(&card.clients) == [20, 21])
+    );
+}
+
+#[test]
+fn udev_card_removed_before_initialized() {
+    let mut state = HotplugState::new(true, [], []);
+
+    state.client_started(client(20, 1), None);
+    state.client_exited(20);
+    state.client_started(client(21, 2), None);
+    state.card_removed(2);
+    state.card_added(card(1, &[]));
+    state.card_added(card(2, &[]));
+
+    let events = drain(&mut state);
+    assert_eq!(events.len(), 2);
+    assert!(
+        matches!(&events[0], HotplugEvent::CardAdded(card) if card.index == 1 && card.clients.is_empty())
+    );
+    assert!(
+        matches!(&events[1], HotplugEvent::CardAdded(card) if card.index == 2 && card.clients.is_empty())
+    );
+}
+
+#[test]
+fn client_started_without_card() {
+    let mut state = HotplugState::new(false, [], []);
+
+    // `Seq::cards` may not list the card of a kernel client
+    state.client_started(client(20, 3), None);
+
+    let events = drain(&mut state);
+    assert_eq!(events.len(), 1);
+    assert!(matches!(&events[0], HotplugEvent::ClientAdded(info) if info.client == 20));
+    assert!(state.has_client(20));
+    assert!(!state.has_card(3));
+
+    state.client_exited(20);
+    let events = drain(&mut state);
+    assert_eq!(events.len(), 1);
+    assert!(matches!(&events[0], HotplugEvent::ClientRemoved(info) if info.client == 20));
+}
+
+#[test]
+fn no_udev_removal() {
+    let mut state = HotplugState::new(false, [card(1, &[20, 21])], [client(30, -1)]);
+
+    state.client_exited(20);
+    state.client_exited(21);
+    state.client_exited(30);
+
+    let events = drain(&mut state);
+    assert_eq!(events.len(), 3);
+    assert!(matches!(&events[0], HotplugEvent::ClientRemoved(info) if info.client == 20));
+    assert!(
+        matches!(&events[1], HotplugEvent::CardRemoved(card) if client_ids(&card.clients) == [21])
+    );
+    assert!(matches!(&events[2], HotplugEvent::ClientRemoved(info) if info.client == 30));
+    assert_eq!(state.cards().count(), 0);
+}
+
+#[test]
+fn ports_of_unknown_clients() {
+    let mut state = HotplugState::new(true, [], [client(30, -1)]);
+
+    state.port_started(PortInfo::new(Addr::new(40, 0)));
+    state.port_exited(Addr::new(40, 0));
+    state.port_exited(Addr::new(30, 1));
+
+    let events = drain(&mut state);
+    assert_eq!(events.len(), 1);
+    assert!(matches!(&events[0], HotplugEvent::PortRemoved(addr) if *addr == Addr::new(30, 1)));
+}
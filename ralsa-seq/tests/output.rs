@@ -0,0 +1,104 @@
+use std::io;
+
+use ralsa_seq::{event::Event, OutputBuffer};
+
+/// `write` taking at most `max` bytes of whole events per call
+fn writer<'a>(written: &'a mut Vec<u8>, max: usize) -> impl FnMut(&[u8]) -> io::Result<usize> + 'a {
+    let cell = Event::note_on(0, 0, 0).encoded_len();
+    move |bytes| {
+        let len = bytes.len().min(max) / cell * cell;
+        if len == 0 {
+            return Err(io::ErrorKind::WouldBlock.into());
+        }
+        written.extend_from_slice(&bytes[..len]);
+        Ok(len)
+    }
+}
+
+#[test]
+fn output_flushes_until_event_fits() {
+    let event = Event::note_on(0, 60, 100);
+    let len = event.encoded_len();
+    let mut buffer = OutputBuffer::new(3 * len);
+    let mut written = Vec::new();
+
+    for _ in 0..3 {
+        buffer.output(&event, writer(&mut written, 0)).unwrap();
+    }
+    assert_eq!(buffer.pending(), 3 * len);
+
+    // one event per write, a single short write makes room
+    assert_eq!(
+        buffer.output(&event, writer(&mut written, len)).unwrap(),
+        3 * len
+    );
+    assert_eq!(written.len(), len);
+
+    // the sequencer takes nothing, the event is not buffered
+    let err = buffer.output(&event, writer(&mut written, 0)).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::WouldBlock);
+    assert_eq!(buffer.pending(), 3 * len);
+
+    let err = buffer.output(&event, |_: &[u8]| Ok(0)).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::WouldBlock);
+    assert_eq!(buffer.pending(), 3 * len);
+
+    assert_eq!(buffer.flush(writer(&mut written, usize::MAX)).unwrap(), 0);
+    assert_eq!(written.len(), 4 * len);
+}
+
+#[test]
+fn output_small_buffer() {
+    let event = Event::note_on(0, 60, 100);
+    let len = event.encoded_len();
+    let mut buffer = OutputBuffer::new(len + len / 2);
+    let mut written = Vec::new();
+
+    buffer.output(&event, writer(&mut written, 0)).unwrap();
+
+    // a partial write of the one event left takes it all, never more than the size
+    for _ in 0..4 {
+        assert_eq!(
+            buffer
+                .output(&event, writer(&mut written, 2 * len - 1))
+                .unwrap(),
+            len
+        );
+    }
+    assert_eq!(written.len(), 4 * len);
+}
+
+#[test]
+fn output_larger_than_buffer() {
+    let data = [0xf0, 1, 2, 3, 4, 5, 6, 7, 0xf7];
+    let sysex = Event::sysex(&data);
+    let mut buffer = OutputBuffer::new(8);
+    let mut written = Vec::new();
+
+    // only taken into an empty buffer
+    assert_eq!(
+        buffer.output(&sysex, writer(&mut written, 0)).unwrap(),
+        sysex.encoded_len()
+    );
+    let err = buffer.output(&sysex, writer(&mut written, 0)).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::WouldBlock);
+}
+
+#[test]
+fn output_flushes_repeatedly() {
+    let event = Event::note_on(0, 60, 100);
+    let len = event.encoded_len();
+    let sysex = Event::sysex(vec![0xf0; len / 2]);
+    let mut buffer = OutputBuffer::new(3 * len);
+    let mut written = Vec::new();
+
+    for _ in 0..3 {
+        buffer.output(&event, writer(&mut written, 0)).unwrap();
+    }
+
+    // the sysex needs room for two events, freed by two short writes
+    let pending = buffer.output(&sysex, writer(&mut written, len)).unwrap();
+    assert_eq!(written.len(), 2 * len);
+    assert_eq!(pending, len + sysex.encoded_len());
+    assert!(pending <= buffer.size());
+}
@@ -0,0 +1,196 @@
+use std::{path::PathBuf, time::Duration};
+
+use alsa_ioctl::seq_ioctl::{
+    Addr, ClientType, MidiVersion, PortCapability, PortDirection, QueueId, SubscribeFlags,
+};
+use ralsa_seq::proc_asound::{self, ProcAsound, ProcSeqPool, ProcSeqSubscriber};
+
+fn fixture(name: &str) -> ProcAsound {
+    ProcAsound::new(
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(name),
+    )
+}
+
+#[test]
+fn cards() {
+    let cards = fixture("proc_asound").cards().unwrap();
+
+    assert_eq!(cards.len(), 2);
+    assert_eq!(cards[0].index, 0);
+    assert_eq!(cards[0].id, "PCH");
+    assert_eq!(cards[0].driver, "HDA-Intel");
+    assert_eq!(cards[0].name, "HDA Intel PCH");
+    assert_eq!(cards[0].long_name, "HDA Intel PCH at 0xf7f10000 irq 32");
+    assert_eq!(cards[1].index, 1);
+    assert_eq!(cards[1].id, "Mini");
+    assert_eq!(cards[1].driver, "USB-Audio");
+    assert_eq!(
+        cards[1].long_name,
+        "Novation Launchkey Mini at usb-0000:00:14.0-2, full speed"
+    );
+
+    assert!(fixture("proc_asound_old").cards().unwrap().is_empty());
+}
+
+#[test]
+fn seq_clients() {
+    let clients = fixture("proc_asound").seq_clients().unwrap();
+
+    assert_eq!(clients.cur_clients, 4);
+    assert_eq!(clients.peak_clients, 5);
+    assert_eq!(clients.max_clients, 192);
+    assert_eq!(clients.clients.len(), 4);
+
+    let system = &clients.clients[0];
+    assert_eq!(system.client, 0);
+    assert_eq!(system.name, "System");
+    assert_eq!(system.type_, ClientType::KERNEL_CLIENT);
+    assert_eq!(system.midi_version, Some(MidiVersion::LEGACY));
+    assert_eq!(system.ports.len(), 2);
+    assert_eq!(system.output_pool, None);
+
+    let announce = &system.ports[1];
+    assert_eq!(announce.addr, Addr::new(0, 1));
+    assert_eq!(announce.name, "Announce");
+    assert_eq!(
+        announce.capability,
+        PortCapability::READ | PortCapability::SUBS_READ
+    );
+    assert_eq!(announce.direction, Some(PortDirection::OUTPUT));
+    assert_eq!(
+        announce.connecting_to,
+        [
+            ProcSeqSubscriber {
+                addr: Addr::new(128, 0),
+                flags: SubscribeFlags::TIMESTAMP,
+                queue: QueueId(0),
+            },
+            ProcSeqSubscriber {
+                addr: Addr::new(129, 0),
+                flags: SubscribeFlags::EXCLUSIVE,
+                queue: QueueId(0),
+            },
+        ]
+    );
+
+    let through = &clients.clients[1];
+    assert_eq!(through.client, 14);
+    assert_eq!(
+        through.output_pool,
+        Some(ProcSeqPool {
+            size: 500,
+            cells_in_use: 0,
+            peak_cells_in_use: 3,
+            alloc_success: 12,
+            alloc_failures: 0,
+        })
+    );
+
+    let usb = &clients.clients[2];
+    assert_eq!(usb.midi_version, Some(MidiVersion::UMP_MIDI_1_0));
+    assert!(usb.ports[1].capability.contains(PortCapability::DUPLEX));
+    assert_eq!(usb.ports[1].direction, Some(PortDirection::BIDIRECTION));
+    assert_eq!(
+        usb.ports[1].connected_from[0].flags,
+        SubscribeFlags::TIMESTAMP | SubscribeFlags::TIME_REAL
+    );
+    assert_eq!(usb.ports[1].connected_from[0].queue, QueueId(1));
+
+    let user = &clients.clients[3];
+    assert_eq!(user.name, "rmidi");
+    assert_eq!(user.type_, ClientType::USER_CLIENT);
+    assert_eq!(user.midi_version, Some(MidiVersion::UMP_MIDI_2_0));
+    assert_eq!(
+        user.ports[0].capability,
+        PortCapability::WRITE | PortCapability::SUBS_WRITE
+    );
+    assert_eq!(user.ports[0].connected_from.len(), 2);
+    assert_eq!(user.output_pool.unwrap().alloc_success, 40);
+    assert_eq!(user.input_pool.unwrap().size, 200);
+    assert_eq!(user.input_pool.unwrap().alloc_failures, 1);
+}
+
+#[test]
+fn seq_clients_line_up_with_ioctl_structs() {
+    let clients = fixture("proc_asound").seq_clients().unwrap();
+    let user = &clients.clients[3];
+
+    let info = user.client_info();
+    assert_eq!(info.client, 128);
+    assert_eq!(info.name.as_str().unwrap(), "rmidi");
+    assert_eq!(info.type_, ClientType::USER_CLIENT);
+    assert_eq!(info.midi_version, MidiVersion::UMP_MIDI_2_0);
+
+    let info = user.ports[0].port_info();
+    assert_eq!(info.addr, Addr::new(128, 0));
+    assert_eq!(info.name.as_str().unwrap(), "input");
+    assert_eq!(info.capability, user.ports[0].capability);
+    assert_eq!(info.direction, PortDirection::INPUT);
+}
+
+#[test]
+fn seq_clients_before_ump() {
+    let clients = fixture("proc_asound_old").seq_clients().unwrap();
+
+    assert_eq!(clients.clients.len(), 2);
+    assert_eq!(clients.clients[0].midi_version, None);
+    assert_eq!(clients.clients[0].ports[0].direction, None);
+    assert_eq!(clients.clients[1].ports[0].name, "Midi Through Port-0");
+    assert!(clients.clients[1].output_pool.is_some());
+}
+
+#[test]
+fn seq_queues() {
+    let queues = fixture("proc_asound").seq_queues().unwrap();
+
+    assert_eq!(queues.len(), 2);
+    assert_eq!(queues[0].queue, QueueId(0));
+    assert_eq!(queues[0].name, "Queue-0");
+    assert_eq!(queues[0].owner, 128);
+    assert!(queues[0].locked);
+    assert_eq!(queues[0].queued_tick_events, 2);
+    assert!(queues[0].running);
+    assert_eq!(queues[0].ppq, 96);
+    assert_eq!(queues[0].tempo, 500000);
+    assert_eq!(queues[0].tempo_base, Some(1000));
+    assert_eq!(queues[0].bpm, 120);
+    assert_eq!(queues[0].time, Duration::new(12, 345678901));
+    assert_eq!(queues[0].tick, 2370);
+
+    assert_eq!(queues[1].name, "rmidi queue");
+    assert!(!queues[1].locked);
+    assert!(!queues[1].running);
+    assert_eq!(queues[1].queued_time_events, 1);
+
+    let queues = fixture("proc_asound_old").seq_queues().unwrap();
+    assert_eq!(queues.len(), 1);
+    assert_eq!(queues[0].tempo_base, None);
+}
+
+#[test]
+fn seq_timers() {
+    let timers = fixture("proc_asound").seq_timers().unwrap();
+
+    assert_eq!(timers.len(), 2);
+    assert_eq!(timers[0].queue, QueueId(0));
+    assert_eq!(timers[0].name, "system timer");
+    assert_eq!(timers[0].period, Duration::from_millis(4));
+    assert_eq!((timers[0].skew_value, timers[0].skew_base), (65536, 65536));
+    assert_eq!(timers[1].name, "HR timer");
+    assert_eq!(timers[1].period, Duration::from_micros(1));
+    assert_eq!(timers[1].skew_value, 70000);
+
+    assert!(fixture("proc_asound_old").seq_timers().unwrap().is_empty());
+}
+
+#[test]
+fn invalid_input() {
+    let err = proc_asound::parse_seq_clients("Client 1 : \"x\" [Alien]\n").unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert!(err.to_string().starts_with("line 1:"));
+
+    assert!(proc_asound::parse_seq_queues("owned by client : 1\n").is_err());
+    assert!(proc_asound::parse_cards(" 0 [x]: broken\n").is_err());
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "serde")]
+
+use alsa_ioctl::seq_ioctl::{Addr, EvCtrl, EvNote, QueueId, QueueSkew};
+use ralsa_seq::event::{Event, EventWithData, QuotedEvent, RawEventData};
+use serde_json::json;
+
+/// `EventWithData` has no `Deserialize`, its payload is borrowed from the
+/// read buffer. It round-trips through the sequencer encoding instead and
+/// has to serialize the same on both ends.
+#[test]
+fn event_with_data() {
+    use EventWithData as E;
+
+    let queue = QueueId(1);
+    let samples = [
+        (
+            E::NoteOn(EvNote::new(9, 36, 127)),
+            json!({"NoteOn": {"channel": 9, "note": 36, "velocity": 127, "off_velocity": 0, "duration": 0}}),
+        ),
+        (
+            E::Controller(EvCtrl::new(0, 7, 100)),
+            json!({"Controller": {"channel": 0, "param": 7, "value": 100}}),
+        ),
+        (
+            E::Tick {
+                queue,
+                position: 384,
+            },
+            json!({"Tick": {"queue": 1, "position": 384}}),
+        ),
+        (
+            E::QueueSkew {
+                queue,
+                skew: QueueSkew {
+                    value: 70000,
+                    base: 65536,
+                },
+            },
+            json!({"QueueSkew": {"queue": 1, "skew": {"value": 70000, "base": 65536}}}),
+        ),
+        (
+            E::Sysex(&[0xf0, 0x7e, 0xf7]),
+            json!({"Sysex": [0xf0, 0x7e, 0xf7]}),
+        ),
+        (
+            E::KernelError(QuotedEvent {
+                origin: Addr::new(130, 0),
+                value: 32,
+            }),
+            json!({"KernelError": {"origin": {"client": 130, "port": 0}, "value": 32}}),
+        ),
+        (E::Reset([0; 12]), json!({"Reset": vec![0; 12]})),
+        (
+            E::Start {
+                queue: QueueId(1),
+                param: [5, 0],
+            },
+            json!({"Start": {"queue": 1, "param": [5, 0]}}),
+        ),
+        (
+            E::Unknown {
+                type_: 200,
+                data: RawEventData::Fixed([1; 12]),
+            },
+            json!({"Unknown": {"type_": 200, "data": {"Fixed": vec![1; 12]}}}),
+        ),
+    ];
+
+    for (sample, expected) in samples {
+        assert_eq!(serde_json::to_value(&sample).unwrap(), expected);
+
+        let mut bytes = Vec::new();
+        Event::from(sample).encode(&mut bytes);
+        let read = Event::read(&bytes).unwrap();
+        assert_eq!(
+            serde_json::to_value(read.event_with_data()).unwrap(),
+            expected
+        );
+    }
+}